@@ -1,11 +1,11 @@
 use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
 use syn::{
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
     spanned::Spanned,
-    Attribute, Error, Field, Fields, Index, ItemStruct, Path, Result, Token, Type, TypeGroup,
-    TypeParen, TypePath,
+    Attribute, Error, Field, Fields, Index, ItemStruct, Path, Result, Token, Type, TypeArray,
+    TypeGroup, TypeParen, TypePath, TypeTuple,
 };
 
 #[derive(Default)]
@@ -44,7 +44,7 @@ impl Parse for LerpAttributes {
     }
 }
 
-fn lerp_field(name: &dyn ToTokens, ty: &Type, attrs: &Vec<Attribute>) -> syn::Result<TokenStream> {
+fn lerp_field(name: &dyn ToTokens, ty: &Type, attrs: &[Attribute]) -> syn::Result<TokenStream> {
     let attr = attrs
         .iter()
         .filter(|attr| attr.path.is_ident("lerp"))
@@ -64,29 +64,78 @@ fn lerp_field(name: &dyn ToTokens, ty: &Type, attrs: &Vec<Attribute>) -> syn::Re
             #name: self.#name
         })
     } else {
-        match ty {
-            Type::Path(TypePath { path, .. }) => Ok({
-                let path = attr.type_override.as_ref().unwrap_or(path);
+        let value = lerp_value(quote!(self.#name), quote!(other.#name), ty, &attr)?;
 
-                if path.is_ident("f64") || path.is_ident("f32") || attr.type_override.is_some() {
-                    quote! {
-                        #name: self.#name.lerp(other.#name, cast::<_, #path>(t).unwrap_or_else(|| panic!("casting any Float to {} should be safe", stringify!(#path))))
-                    }
-                } else {
-                    quote! {
-                        #name: self.#name.lerp(other.#name, t)
-                    }
-                }
-            }),
+        Ok(quote! {
+            #name: #value
+        })
+    }
+}
 
-            // Recursively descend through groups
-            Type::Group(TypeGroup { elem, .. }) | Type::Paren(TypeParen { elem, .. }) => {
-                lerp_field(name, elem, attrs)
+fn lerp_value(
+    this: TokenStream,
+    other: TokenStream,
+    ty: &Type,
+    attr: &LerpAttributes,
+) -> syn::Result<TokenStream> {
+    match ty {
+        Type::Path(TypePath { path, .. }) => Ok({
+            let path = attr.type_override.as_ref().unwrap_or(path);
+
+            if path.is_ident("f64") || path.is_ident("f32") || attr.type_override.is_some() {
+                quote! {
+                    #this.lerp(#other, cast::<_, #path>(t).unwrap_or_else(|| panic!("casting any Float to {} should be safe", stringify!(#path))))
+                }
+            } else {
+                quote! {
+                    #this.lerp(#other, t)
+                }
             }
+        }),
 
-            // TODO: Support the types outlined in issue #6 <https://github.com/coriolinus/lerp-rs/issues/6>
-            _ => Err(Error::new(ty.span(), "Unsupported type.\nSee issue #6 <https://github.com/coriolinus/lerp-rs/issues/6> for more information")),
+        // Recursively descend through groups
+        Type::Group(TypeGroup { elem, .. }) | Type::Paren(TypeParen { elem, .. }) => {
+            lerp_value(this, other, elem, attr)
         }
+
+        // Lerp arrays element-wise
+        Type::Array(TypeArray { elem, .. }) => {
+            let element = lerp_value(quote!(this), quote!(other), elem, attr)?;
+
+            Ok(quote! {{
+                let mut this = ::core::iter::IntoIterator::into_iter(#this);
+                let mut other = ::core::iter::IntoIterator::into_iter(#other);
+                ::core::array::from_fn(|_| {
+                    let this = this.next().expect("arrays of the same type have the same length");
+                    let other = other.next().expect("arrays of the same type have the same length");
+                    #element
+                })
+            }})
+        }
+
+        // Lerp tuples element-wise
+        Type::Tuple(TypeTuple { elems, .. }) => {
+            let these = (0..elems.len())
+                .map(|i| format_ident!("this_{}", i))
+                .collect::<Vec<_>>();
+            let others = (0..elems.len())
+                .map(|i| format_ident!("other_{}", i))
+                .collect::<Vec<_>>();
+            let values = elems
+                .iter()
+                .zip(these.iter().zip(&others))
+                .map(|(elem, (this, other))| lerp_value(quote!(#this), quote!(#other), elem, attr))
+                .collect::<Result<Vec<_>>>()?;
+
+            Ok(quote! {{
+                let (#(#these,)*) = #this;
+                let (#(#others,)*) = #other;
+                (#(#values,)*)
+            }})
+        }
+
+        // TODO: Support the types outlined in issue #6 <https://github.com/coriolinus/lerp-rs/issues/6>
+        _ => Err(Error::new(ty.span(), "Unsupported type.\nSee issue #6 <https://github.com/coriolinus/lerp-rs/issues/6> for more information")),
     }
 }
 
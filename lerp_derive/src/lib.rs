@@ -21,6 +21,9 @@ mod derive;
 /// not derive lerp) you can use the `#[lerp(skip)]` or `#[lerp(ignore)]` attributes
 /// which will produce the value, untouched from the left value.
 ///
+/// Fields which are arrays or tuples are lerped element-wise, with any type
+/// attribute applying to each element.
+///
 /// Not all types are supported in this derive macro. See [the github issue] for
 /// discussion and more information
 ///
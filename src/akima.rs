@@ -0,0 +1,85 @@
+//! Akima spline interpolation, which resists wiggling near outliers.
+
+use crate::float::constant;
+use crate::piecewise::{check_knots, piecewise_cubic_methods, secants, PiecewiseCubic};
+use crate::SplineError;
+use num_traits::Float;
+
+/// An Akima spline through a table of `(x, y)` knots.
+///
+/// The slope at each knot is a weighted average of the secants on either side, chosen
+/// from the five nearest knots so that an outlier only disturbs the curve nearby.
+/// Ordinary cubic splines instead spread the influence of every knot across the whole
+/// curve, so a single outlier causes ripples far away. Where three consecutive knots
+/// are collinear, the curve follows their line exactly.
+///
+/// Like a [`MonotoneCubic`](crate::MonotoneCubic), only the first derivative is continuous.
+///
+/// # Example
+///
+/// ```
+/// use lerp::AkimaSpline;
+///
+/// let xs = vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+/// let ys = vec![0.0, 0.0, 0.0, 5.0, 0.0, 0.0, 0.0];
+/// let spline = AkimaSpline::new(xs, ys).unwrap();
+///
+/// // the outlier at 3 does not disturb the flat data two knots away
+/// assert_eq!(spline.eval(0.5), 0.0);
+/// assert_eq!(spline.eval(5.5), 0.0);
+/// assert_eq!(spline.eval_bounded(10.0), 0.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct AkimaSpline<F> {
+    curve: PiecewiseCubic<F>,
+}
+
+impl<F: Float> AkimaSpline<F> {
+    /// Fit an Akima spline through the knots `(xs[i], ys[i])`.
+    ///
+    /// `xs` must be strictly increasing, and there must be at least two knots.
+    /// With two knots, the spline is the line through them.
+    pub fn new(xs: Vec<F>, ys: Vec<F>) -> Result<AkimaSpline<F>, SplineError> {
+        check_knots(&xs, &ys)?;
+        let slopes = slopes(&xs, &ys);
+        Ok(AkimaSpline {
+            curve: PiecewiseCubic::new(xs, ys, slopes),
+        })
+    }
+
+    piecewise_cubic_methods!();
+}
+
+fn slopes<F: Float>(xs: &[F], ys: &[F]) -> Vec<F> {
+    let secant = secants(xs, ys);
+    if secant.len() == 1 {
+        return vec![secant[0]; 2];
+    }
+
+    // extend the secants by two on each side, continuing their linear trend
+    let s = secant.len();
+    let mut extended = Vec::with_capacity(s + 4);
+    let before = secant[0] + secant[0] - secant[1];
+    extended.push(before + before - secant[0]);
+    extended.push(before);
+    extended.extend_from_slice(&secant);
+    let after = secant[s - 1] + secant[s - 1] - secant[s - 2];
+    extended.push(after);
+    extended.push(after + after - secant[s - 1]);
+
+    // the slope at knot `i` weights the secants on either side, `extended[i + 1]` and
+    // `extended[i + 2]`, by how much the secants change on the opposite side
+    extended
+        .windows(4)
+        .map(|m| {
+            let left_weight = (m[3] - m[2]).abs();
+            let right_weight = (m[1] - m[0]).abs();
+            let total = left_weight + right_weight;
+            if total > F::zero() {
+                (left_weight * m[1] + right_weight * m[2]) / total
+            } else {
+                (m[1] + m[2]) / constant(2.0)
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,127 @@
+//! Batched lerping over floating point buffers.
+
+use crate::slice::check_lengths;
+use crate::{LengthMismatch, Lerp};
+
+/// Floating point types which can be lerped in batches.
+///
+/// With the `simd` feature enabled, batches are processed several lanes at a time using
+/// portable SIMD on stable Rust. Otherwise, a scalar loop is used. In either case, the
+/// results are identical to calling [`Lerp::lerp`] on each element.
+///
+/// All slices must have the same length; otherwise, `out` is left untouched.
+pub trait LerpBatch: Sized {
+    /// Lerp each element of `a` towards the corresponding element of `b` by `t`,
+    /// writing the results to `out`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lerp::LerpBatch;
+    ///
+    /// let a = [0.0_f32; 10];
+    /// let b = [2.0_f32; 10];
+    /// let mut out = [0.0; 10];
+    /// f32::lerp_batch(&a, &b, 0.25, &mut out).unwrap();
+    /// assert_eq!(out, [0.5; 10]);
+    /// ```
+    fn lerp_batch(a: &[Self], b: &[Self], t: Self, out: &mut [Self]) -> Result<(), LengthMismatch>;
+
+    /// Lerp each element of `a` towards the corresponding element of `b` by the
+    /// corresponding element of `t`, writing the results to `out`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lerp::LerpBatch;
+    ///
+    /// let mut out = [0.0; 3];
+    /// f64::lerp_batch_each(&[0.0; 3], &[4.0; 3], &[0.0, 0.5, 1.0], &mut out).unwrap();
+    /// assert_eq!(out, [0.0, 2.0, 4.0]);
+    /// ```
+    fn lerp_batch_each(
+        a: &[Self],
+        b: &[Self],
+        t: &[Self],
+        out: &mut [Self],
+    ) -> Result<(), LengthMismatch>;
+}
+
+macro_rules! impl_lerp_batch {
+    ($($float:ident => $simd:ident[$lanes:expr]),*) => {
+        $(
+            impl LerpBatch for $float {
+                fn lerp_batch(
+                    a: &[$float],
+                    b: &[$float],
+                    t: $float,
+                    out: &mut [$float],
+                ) -> Result<(), LengthMismatch> {
+                    check_lengths(a.len(), &[b.len(), out.len()])?;
+
+                    #[cfg(feature = "simd")]
+                    let (a, b, out) = {
+                        use wide::$simd;
+
+                        let mut a = a.chunks_exact($lanes);
+                        let mut b = b.chunks_exact($lanes);
+                        let mut out = out.chunks_exact_mut($lanes);
+                        for ((out, a), b) in (&mut out).zip(&mut a).zip(&mut b) {
+                            let a = $simd::new(to_lanes(a));
+                            let b = $simd::new(to_lanes(b));
+                            out.copy_from_slice(&a.lerp(b, t).to_array());
+                        }
+                        (a.remainder(), b.remainder(), out.into_remainder())
+                    };
+
+                    for ((out, &a), &b) in out.iter_mut().zip(a).zip(b) {
+                        *out = a.lerp(b, t);
+                    }
+                    Ok(())
+                }
+
+                fn lerp_batch_each(
+                    a: &[$float],
+                    b: &[$float],
+                    t: &[$float],
+                    out: &mut [$float],
+                ) -> Result<(), LengthMismatch> {
+                    check_lengths(a.len(), &[b.len(), t.len(), out.len()])?;
+
+                    #[cfg(feature = "simd")]
+                    let (a, b, t, out) = {
+                        use wide::$simd;
+
+                        let one = $simd::splat(1.0);
+                        let mut a = a.chunks_exact($lanes);
+                        let mut b = b.chunks_exact($lanes);
+                        let mut t = t.chunks_exact($lanes);
+                        let mut out = out.chunks_exact_mut($lanes);
+                        for (((out, a), b), t) in (&mut out).zip(&mut a).zip(&mut b).zip(&mut t) {
+                            let a = $simd::new(to_lanes(a));
+                            let b = $simd::new(to_lanes(b));
+                            let t = $simd::new(to_lanes(t));
+                            // the same formula as the default implementation of `Lerp`
+                            out.copy_from_slice(&(a * (one - t) + b * t).to_array());
+                        }
+                        (a.remainder(), b.remainder(), t.remainder(), out.into_remainder())
+                    };
+
+                    for (((out, &a), &b), &t) in out.iter_mut().zip(a).zip(b).zip(t) {
+                        *out = a.lerp(b, t);
+                    }
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_lerp_batch!(f32 => f32x8[8], f64 => f64x4[4]);
+
+#[cfg(feature = "simd")]
+fn to_lanes<T: Copy + Default, const N: usize>(chunk: &[T]) -> [T; N] {
+    let mut lanes = [T::default(); N];
+    lanes.copy_from_slice(chunk);
+    lanes
+}
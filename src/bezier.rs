@@ -0,0 +1,347 @@
+//! Bézier curves, evaluated by repeated lerping.
+
+use crate::{Curve, Lerp};
+use std::ops::{Add, Sub};
+
+/// `(b - a) * n`, for `n >= 1`, computed by doubling and adding so that no scalar type is
+/// needed. This takes `O(log n)` additions, each of them exact when doubling floats.
+fn scaled_difference<T>(a: T, b: T, n: usize) -> T
+where
+    T: Add<Output = T> + Sub<Output = T> + Copy,
+{
+    debug_assert!(n >= 1);
+    let mut power = b - a;
+    let mut sum = None;
+    let mut n = n;
+    loop {
+        if n & 1 == 1 {
+            sum = Some(sum.map_or(power, |sum| sum + power));
+        }
+        n >>= 1;
+        if n == 0 {
+            break;
+        }
+        power = power + power;
+    }
+    sum.expect("n is at least one")
+}
+
+/// The number of control points which [`Bezier`] evaluates without allocating.
+const STACK_POINTS: usize = 8;
+
+/// Reduce `row` to a single point at `t` by de Casteljau's algorithm, in place.
+///
+/// Calls `visit` with the first and last points of each successive row, including the
+/// initial one. The point is left in `row[0]`.
+fn de_casteljau<T, F>(row: &mut [T], t: F, mut visit: impl FnMut(T, T))
+where
+    T: Lerp<F> + Copy,
+    F: Copy,
+{
+    for len in (1..=row.len()).rev() {
+        visit(row[0], row[len - 1]);
+        for i in 0..len - 1 {
+            row[i] = row[i].lerp(row[i + 1], t);
+        }
+    }
+}
+
+/// A linear Bézier curve: the straight line from `p0` to `p1`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct LinearBezier<T> {
+    /// The start point.
+    pub p0: T,
+    /// The end point.
+    pub p1: T,
+}
+
+impl<T: Copy> LinearBezier<T> {
+    /// Create a curve from its control points.
+    pub fn new(p0: T, p1: T) -> LinearBezier<T> {
+        LinearBezier { p0, p1 }
+    }
+
+    /// Split this curve at `t` into two curves which together trace the same path.
+    pub fn split<F>(&self, t: F) -> (Self, Self)
+    where
+        T: Lerp<F>,
+    {
+        let mid = self.p0.lerp(self.p1, t);
+        (
+            LinearBezier::new(self.p0, mid),
+            LinearBezier::new(mid, self.p1),
+        )
+    }
+
+    /// The derivative of this curve with respect to `t`, which is constant.
+    pub fn derivative(&self) -> T
+    where
+        T: Sub<Output = T>,
+    {
+        self.p1 - self.p0
+    }
+}
+
+impl<T, F> Curve<F> for LinearBezier<T>
+where
+    T: Lerp<F> + Copy,
+{
+    type Point = T;
+
+    fn eval(&self, t: F) -> T {
+        self.p0.lerp(self.p1, t)
+    }
+}
+
+/// A quadratic Bézier curve from `p0` to `p2`, pulled towards `p1`.
+///
+/// Any `T: Lerp<F> + Copy` can be a control point.
+///
+/// # Example
+///
+/// ```
+/// use lerp::{Curve, QuadBezier};
+///
+/// let curve = QuadBezier::new(0.0, 4.0, 0.0);
+/// assert_eq!(curve.eval(0.5), 2.0);
+/// assert_eq!(curve.derivative().eval(0.0), 8.0);
+///
+/// let (left, right) = curve.split(0.5);
+/// assert_eq!(left, QuadBezier::new(0.0, 2.0, 2.0));
+/// assert_eq!(right, QuadBezier::new(2.0, 2.0, 0.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct QuadBezier<T> {
+    /// The start point.
+    pub p0: T,
+    /// The control point.
+    pub p1: T,
+    /// The end point.
+    pub p2: T,
+}
+
+impl<T: Copy> QuadBezier<T> {
+    /// Create a curve from its control points.
+    pub fn new(p0: T, p1: T, p2: T) -> QuadBezier<T> {
+        QuadBezier { p0, p1, p2 }
+    }
+
+    /// Split this curve at `t` into two curves which together trace the same path.
+    pub fn split<F>(&self, t: F) -> (Self, Self)
+    where
+        T: Lerp<F>,
+        F: Copy,
+    {
+        let p01 = self.p0.lerp(self.p1, t);
+        let p12 = self.p1.lerp(self.p2, t);
+        let p012 = p01.lerp(p12, t);
+        (
+            QuadBezier::new(self.p0, p01, p012),
+            QuadBezier::new(p012, p12, self.p2),
+        )
+    }
+
+    /// The derivative of this curve with respect to `t`.
+    pub fn derivative(&self) -> LinearBezier<T>
+    where
+        T: Add<Output = T> + Sub<Output = T>,
+    {
+        LinearBezier::new(
+            scaled_difference(self.p0, self.p1, 2),
+            scaled_difference(self.p1, self.p2, 2),
+        )
+    }
+}
+
+impl<T, F> Curve<F> for QuadBezier<T>
+where
+    T: Lerp<F> + Copy,
+    F: Copy,
+{
+    type Point = T;
+
+    fn eval(&self, t: F) -> T {
+        self.split(t).0.p2
+    }
+}
+
+/// A cubic Bézier curve from `p0` to `p3`, pulled towards `p1` and then `p2`.
+///
+/// Any `T: Lerp<F> + Copy` can be a control point.
+///
+/// # Example
+///
+/// ```
+/// use lerp::{CubicBezier, Curve, CurveIter};
+///
+/// let curve = CubicBezier::new(0.0, 0.0, 1.0, 1.0);
+/// assert_eq!(curve.eval(0.5), 0.5);
+///
+/// let points: Vec<f64> = curve.sample_iter_closed(3).collect();
+/// assert_eq!(points, vec![0.0, 0.5, 1.0]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct CubicBezier<T> {
+    /// The start point.
+    pub p0: T,
+    /// The first control point.
+    pub p1: T,
+    /// The second control point.
+    pub p2: T,
+    /// The end point.
+    pub p3: T,
+}
+
+impl<T: Copy> CubicBezier<T> {
+    /// Create a curve from its control points.
+    pub fn new(p0: T, p1: T, p2: T, p3: T) -> CubicBezier<T> {
+        CubicBezier { p0, p1, p2, p3 }
+    }
+
+    /// Split this curve at `t` into two curves which together trace the same path.
+    pub fn split<F>(&self, t: F) -> (Self, Self)
+    where
+        T: Lerp<F>,
+        F: Copy,
+    {
+        let p01 = self.p0.lerp(self.p1, t);
+        let p12 = self.p1.lerp(self.p2, t);
+        let p23 = self.p2.lerp(self.p3, t);
+        let p012 = p01.lerp(p12, t);
+        let p123 = p12.lerp(p23, t);
+        let p0123 = p012.lerp(p123, t);
+        (
+            CubicBezier::new(self.p0, p01, p012, p0123),
+            CubicBezier::new(p0123, p123, p23, self.p3),
+        )
+    }
+
+    /// The derivative of this curve with respect to `t`.
+    pub fn derivative(&self) -> QuadBezier<T>
+    where
+        T: Add<Output = T> + Sub<Output = T>,
+    {
+        QuadBezier::new(
+            scaled_difference(self.p0, self.p1, 3),
+            scaled_difference(self.p1, self.p2, 3),
+            scaled_difference(self.p2, self.p3, 3),
+        )
+    }
+}
+
+impl<T, F> Curve<F> for CubicBezier<T>
+where
+    T: Lerp<F> + Copy,
+    F: Copy,
+{
+    type Point = T;
+
+    fn eval(&self, t: F) -> T {
+        self.split(t).0.p3
+    }
+}
+
+/// A Bézier curve of arbitrary degree.
+///
+/// A curve with `n + 1` control points has degree `n`.
+///
+/// # Example
+///
+/// ```
+/// use lerp::{Bezier, Curve};
+///
+/// let curve = Bezier::new(vec![0.0, 4.0, -4.0, 0.0, 16.0]).unwrap();
+/// assert_eq!(curve.degree(), 4);
+/// assert_eq!(curve.eval(0.0), 0.0);
+/// assert_eq!(curve.eval(1.0), 16.0);
+/// assert_eq!(curve.derivative().unwrap().eval(1.0), 64.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bezier<T> {
+    points: Vec<T>,
+}
+
+impl<T: Copy> Bezier<T> {
+    /// Create a curve from its control points.
+    ///
+    /// Returns `None` if there are no control points.
+    pub fn new(points: Vec<T>) -> Option<Bezier<T>> {
+        if points.is_empty() {
+            None
+        } else {
+            Some(Bezier { points })
+        }
+    }
+
+    /// The control points of this curve.
+    pub fn points(&self) -> &[T] {
+        &self.points
+    }
+
+    /// The degree of this curve: one less than the number of control points.
+    pub fn degree(&self) -> usize {
+        self.points.len() - 1
+    }
+
+    /// Split this curve at `t` into two curves which together trace the same path.
+    pub fn split<F>(&self, t: F) -> (Self, Self)
+    where
+        T: Lerp<F>,
+        F: Copy,
+    {
+        // the left curve takes the first point of each successive row of lerps,
+        // and the right curve takes the last.
+        let mut row = self.points.clone();
+        let mut left = Vec::with_capacity(row.len());
+        let mut right = Vec::with_capacity(row.len());
+        de_casteljau(&mut row, t, |first, last| {
+            left.push(first);
+            right.push(last);
+        });
+        right.reverse();
+        (Bezier { points: left }, Bezier { points: right })
+    }
+
+    /// The derivative of this curve with respect to `t`.
+    ///
+    /// Returns `None` for a curve of degree zero, whose derivative is zero.
+    ///
+    /// Each control point of the derivative is a difference of control points scaled by
+    /// the degree `n`. As no scalar type is required, that scaling takes `O(log n)`
+    /// additions rather than a single multiplication.
+    pub fn derivative(&self) -> Option<Bezier<T>>
+    where
+        T: Add<Output = T> + Sub<Output = T>,
+    {
+        let n = self.degree();
+        Bezier::new(
+            self.points
+                .windows(2)
+                .map(|pair| scaled_difference(pair[0], pair[1], n))
+                .collect(),
+        )
+    }
+}
+
+impl<T, F> Curve<F> for Bezier<T>
+where
+    T: Lerp<F> + Copy,
+    F: Copy,
+{
+    type Point = T;
+
+    fn eval(&self, t: F) -> T {
+        let points = &self.points[..];
+        if points.len() <= STACK_POINTS {
+            let mut row = [points[0]; STACK_POINTS];
+            let row = &mut row[..points.len()];
+            row.copy_from_slice(points);
+            de_casteljau(row, t, |_, _| {});
+            row[0]
+        } else {
+            let mut row = points.to_vec();
+            de_casteljau(&mut row, t, |_, _| {});
+            row[0]
+        }
+    }
+}
@@ -0,0 +1,238 @@
+//! Catmull-Rom splines, which pass through every control point.
+
+use crate::float::constant;
+use crate::{Curve, Lerp, Vector3};
+use num_traits::Float;
+
+/// Types with a notion of distance between two values.
+///
+/// Centripetal and chordal Catmull-Rom splines space their knots by the distance
+/// between consecutive control points.
+///
+/// It's implemented for all `F: Float`, and for [`Vector3`].
+pub trait Distance<F> {
+    /// The distance between `self` and `other`.
+    fn distance(&self, other: &Self) -> F;
+}
+
+impl<F: Float> Distance<F> for F {
+    fn distance(&self, other: &F) -> F {
+        (*self - *other).abs()
+    }
+}
+
+impl<F: Float> Distance<F> for Vector3<F> {
+    fn distance(&self, other: &Vector3<F>) -> F {
+        (*self - *other).length()
+    }
+}
+
+/// How a [`CatmullRom`] spline spaces its knots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CatmullRomKind {
+    /// Knots are evenly spaced. This is the classic Catmull-Rom spline, but it can
+    /// overshoot, form cusps, and self-intersect when control points are unevenly spaced.
+    Uniform,
+    /// Knots are spaced by the square root of the distance between control points.
+    /// This never forms cusps or self-intersections within a segment.
+    Centripetal,
+    /// Knots are spaced by the distance between control points.
+    Chordal,
+}
+
+impl CatmullRomKind {
+    fn alpha<F: Float>(self) -> F {
+        match self {
+            CatmullRomKind::Uniform => F::zero(),
+            CatmullRomKind::Centripetal => constant(0.5),
+            CatmullRomKind::Chordal => F::one(),
+        }
+    }
+}
+
+/// A Catmull-Rom spline, which passes smoothly through each of its control points.
+///
+/// Each segment between two consecutive control points is shaped by its neighbors.
+/// The first and last segments have only one neighbor, so a phantom control point is
+/// added beyond each end by reflecting its neighbor: `p0.lerp(p1, -1.0)`.
+///
+/// Evaluation uses the Barry-Goldman pyramidal formulation, which consists only of lerps,
+/// so any `T: Lerp<F> + Copy` can be a control point.
+///
+/// As a [`Curve`], `t == 0.0` is the first control point and `t == 1.0` is the last,
+/// with each segment taking an equal share of `t`. `t` is unbounded: outside [0..1],
+/// the first or last segment is extrapolated.
+///
+/// # Example
+///
+/// ```
+/// use lerp::{CatmullRom, Curve, CurveIter};
+///
+/// let spline = CatmullRom::centripetal(vec![0.0, 1.0, 3.0, 4.0]).unwrap();
+/// assert_eq!(spline.segment_count(), 3);
+/// assert_eq!(spline.eval(0.0), 0.0);
+/// assert_eq!(spline.segment(1, 1.0), Some(3.0));
+///
+/// let points: Vec<f64> = spline.sample_iter_closed(4).collect();
+/// assert_eq!(points, vec![0.0, 1.0, 3.0, 4.0]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CatmullRom<T, F> {
+    // the control points, including a phantom point at each end
+    points: Vec<T>,
+    // one knot per point in `points`
+    knots: Vec<F>,
+}
+
+impl<T, F> CatmullRom<T, F>
+where
+    T: Lerp<F> + Copy,
+    F: Float,
+{
+    /// Create a uniform Catmull-Rom spline through `points`.
+    ///
+    /// Returns `None` if there are fewer than two points.
+    pub fn uniform(points: Vec<T>) -> Option<CatmullRom<T, F>> {
+        Self::from_intervals(points, |_, _| F::one())
+    }
+
+    /// Create a centripetal Catmull-Rom spline through `points`.
+    ///
+    /// Returns `None` if there are fewer than two points.
+    pub fn centripetal(points: Vec<T>) -> Option<CatmullRom<T, F>>
+    where
+        T: Distance<F>,
+    {
+        Self::new(points, CatmullRomKind::Centripetal)
+    }
+
+    /// Create a chordal Catmull-Rom spline through `points`.
+    ///
+    /// Returns `None` if there are fewer than two points.
+    pub fn chordal(points: Vec<T>) -> Option<CatmullRom<T, F>>
+    where
+        T: Distance<F>,
+    {
+        Self::new(points, CatmullRomKind::Chordal)
+    }
+
+    /// Create a Catmull-Rom spline of the given kind through `points`.
+    ///
+    /// Consecutive control points which coincide are spaced as in a uniform spline.
+    ///
+    /// Returns `None` if there are fewer than two points.
+    pub fn new(points: Vec<T>, kind: CatmullRomKind) -> Option<CatmullRom<T, F>>
+    where
+        T: Distance<F>,
+    {
+        let alpha = kind.alpha::<F>();
+        Self::from_intervals(points, |a, b| {
+            let interval = a.distance(b).powf(alpha);
+            if interval > F::zero() && interval.is_finite() {
+                interval
+            } else {
+                F::one()
+            }
+        })
+    }
+
+    fn from_intervals(points: Vec<T>, interval: impl Fn(&T, &T) -> F) -> Option<CatmullRom<T, F>> {
+        let n = points.len();
+        if n < 2 {
+            return None;
+        }
+        let mut extended = Vec::with_capacity(n + 2);
+        extended.push(points[0].lerp(points[1], -F::one()));
+        extended.extend_from_slice(&points);
+        extended.push(points[n - 1].lerp(points[n - 2], -F::one()));
+
+        let mut knots = Vec::with_capacity(extended.len());
+        knots.push(F::zero());
+        for pair in extended.windows(2) {
+            let last = knots[knots.len() - 1];
+            knots.push(last + interval(&pair[0], &pair[1]));
+        }
+
+        Some(CatmullRom {
+            points: extended,
+            knots,
+        })
+    }
+
+    /// The control points this spline passes through.
+    pub fn points(&self) -> &[T] {
+        &self.points[1..self.points.len() - 1]
+    }
+
+    /// The number of segments in this spline: one less than the number of control points.
+    pub fn segment_count(&self) -> usize {
+        self.points.len() - 3
+    }
+
+    /// Evaluate segment `index`, which runs from control point `index` to control point
+    /// `index + 1` as `t` runs from `0.0` to `1.0`.
+    ///
+    /// `t` is unbounded.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn segment(&self, index: usize, t: F) -> Option<T> {
+        if index >= self.segment_count() {
+            return None;
+        }
+        Some(barry_goldman(
+            &self.points[index..index + 4],
+            &self.knots[index..index + 4],
+            t,
+        ))
+    }
+}
+
+/// Evaluate the Catmull-Rom segment from `p[1]` to `p[2]` with the Barry-Goldman
+/// pyramidal formulation, where `k` holds the knot of each point in `p`.
+///
+/// `t` runs from `0.0` at `p[1]` to `1.0` at `p[2]`.
+pub(crate) fn barry_goldman<T, F>(p: &[T], k: &[F], t: F) -> T
+where
+    T: Lerp<F> + Copy,
+    F: Float,
+{
+    // the pyramid below only reaches the control points to within rounding error
+    if t == F::zero() {
+        return p[1];
+    }
+    if t == F::one() {
+        return p[2];
+    }
+    let time = k[1] + (k[2] - k[1]) * t;
+    let at = |start: F, end: F| (time - start) / (end - start);
+
+    let a1 = p[0].lerp(p[1], at(k[0], k[1]));
+    let a2 = p[1].lerp(p[2], t);
+    let a3 = p[2].lerp(p[3], at(k[2], k[3]));
+    let b1 = a1.lerp(a2, at(k[0], k[2]));
+    let b2 = a2.lerp(a3, at(k[1], k[3]));
+    b1.lerp(b2, t)
+}
+
+impl<T, F> Curve<F> for CatmullRom<T, F>
+where
+    T: Lerp<F> + Copy,
+    F: Float,
+{
+    type Point = T;
+
+    fn eval(&self, t: F) -> T {
+        let segments = self.segment_count();
+        let position = t * F::from(segments).expect("segment count is representable");
+        // NaN and negative positions fall into the first segment, infinite ones into the last
+        let index = position
+            .floor()
+            .max(F::zero())
+            .to_usize()
+            .unwrap_or(segments)
+            .min(segments - 1);
+        let start = F::from(index).expect("segment index is representable");
+        self.segment(index, position - start)
+            .expect("index is bounded by the segment count")
+    }
+}
@@ -0,0 +1,156 @@
+//! Interpolating cubic splines, which are continuous in their second derivative.
+
+use crate::float::constant;
+use crate::piecewise::{check_knots, piecewise_cubic_methods, secants, PiecewiseCubic};
+use crate::SplineError;
+use num_traits::Float;
+
+/// The condition which determines the shape of a [`CubicSpline`] at its ends.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SplineBoundary<F> {
+    /// The second derivative is zero at both ends.
+    Natural,
+    /// The first derivative at each end is fixed.
+    Clamped {
+        /// The slope `dy/dx` at the first knot.
+        start: F,
+        /// The slope `dy/dx` at the last knot.
+        end: F,
+    },
+    /// The third derivative is continuous across the second and second-to-last knots,
+    /// so the first two and last two segments are each a single cubic.
+    ///
+    /// With three knots, the spline is the parabola through them.
+    /// With two knots, it is the line through them.
+    NotAKnot,
+}
+
+/// A cubic spline through a table of `(x, y)` knots.
+///
+/// The spline passes through every knot, and its first and second derivatives are
+/// continuous everywhere.
+///
+/// # Example
+///
+/// ```
+/// use lerp::{CubicSpline, SplineBoundary};
+///
+/// let xs = vec![0.0_f64, 1.0, 2.0, 3.0];
+/// let ys = vec![0.0, 1.0, 8.0, 27.0];
+/// let spline = CubicSpline::new(xs, ys, SplineBoundary::NotAKnot).unwrap();
+///
+/// // not-a-knot splines reproduce cubics exactly
+/// assert!((spline.eval(1.5) - 3.375).abs() < 1e-12);
+/// assert!((spline.derivative(1.5) - 6.75).abs() < 1e-12);
+/// assert!((spline.eval(4.0) - 64.0).abs() < 1e-12);
+/// assert_eq!(spline.eval_bounded(4.0), 27.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CubicSpline<F> {
+    curve: PiecewiseCubic<F>,
+}
+
+impl<F: Float> CubicSpline<F> {
+    /// Fit a cubic spline through the knots `(xs[i], ys[i])`.
+    ///
+    /// `xs` must be strictly increasing, and there must be at least two knots.
+    pub fn new(
+        xs: Vec<F>,
+        ys: Vec<F>,
+        boundary: SplineBoundary<F>,
+    ) -> Result<CubicSpline<F>, SplineError> {
+        check_knots(&xs, &ys)?;
+        let slopes = slopes(&xs, &ys, boundary);
+        Ok(CubicSpline {
+            curve: PiecewiseCubic::new(xs, ys, slopes),
+        })
+    }
+
+    piecewise_cubic_methods!();
+}
+
+/// The slope at each knot which makes the second derivative continuous.
+fn slopes<F: Float>(xs: &[F], ys: &[F], boundary: SplineBoundary<F>) -> Vec<F> {
+    let n = xs.len();
+    let dx: Vec<F> = xs.windows(2).map(|pair| pair[1] - pair[0]).collect();
+    let secant = secants(xs, ys);
+    let two = constant::<F>(2.0);
+    let three = constant::<F>(3.0);
+
+    match (boundary, n) {
+        (SplineBoundary::Natural, 2) | (SplineBoundary::NotAKnot, 2) => {
+            return vec![secant[0]; 2];
+        }
+        (SplineBoundary::NotAKnot, 3) => {
+            // the parabola through all three knots
+            let curvature = (secant[1] - secant[0]) / (xs[2] - xs[0]);
+            return xs
+                .iter()
+                .map(|&x| secant[0] + curvature * (x + x - xs[0] - xs[1]))
+                .collect();
+        }
+        _ => {}
+    }
+
+    // Each interior knot contributes one equation in the slopes of its neighbors:
+    // `dx[i] * s[i-1] + 2 * (dx[i-1] + dx[i]) * s[i] + dx[i-1] * s[i+1] = rhs[i]`
+    let mut lower = vec![F::zero(); n];
+    let mut diagonal = vec![F::zero(); n];
+    let mut upper = vec![F::zero(); n];
+    let mut rhs = vec![F::zero(); n];
+    for i in 1..n - 1 {
+        lower[i] = dx[i];
+        diagonal[i] = two * (dx[i - 1] + dx[i]);
+        upper[i] = dx[i - 1];
+        rhs[i] = three * (dx[i] * secant[i - 1] + dx[i - 1] * secant[i]);
+    }
+
+    match boundary {
+        SplineBoundary::Natural => {
+            diagonal[0] = two;
+            upper[0] = F::one();
+            rhs[0] = three * secant[0];
+            lower[n - 1] = F::one();
+            diagonal[n - 1] = two;
+            rhs[n - 1] = three * secant[n - 2];
+        }
+        SplineBoundary::Clamped { start, end } => {
+            diagonal[0] = F::one();
+            rhs[0] = start;
+            diagonal[n - 1] = F::one();
+            rhs[n - 1] = end;
+        }
+        SplineBoundary::NotAKnot => {
+            let span = xs[2] - xs[0];
+            diagonal[0] = dx[1];
+            upper[0] = span;
+            rhs[0] = ((dx[0] + two * span) * dx[1] * secant[0] + dx[0] * dx[0] * secant[1]) / span;
+
+            let span = xs[n - 1] - xs[n - 3];
+            let (last, before) = (dx[n - 2], dx[n - 3]);
+            lower[n - 1] = span;
+            diagonal[n - 1] = before;
+            rhs[n - 1] =
+                (last * last * secant[n - 3] + (two * span + last) * before * secant[n - 2]) / span;
+        }
+    }
+
+    solve_tridiagonal(&lower, &mut diagonal, &upper, &mut rhs);
+    rhs
+}
+
+/// Solve a tridiagonal system in place with the Thomas algorithm, leaving the solution in `rhs`.
+///
+/// `lower[0]` and `upper[n - 1]` are ignored.
+fn solve_tridiagonal<F: Float>(lower: &[F], diagonal: &mut [F], upper: &[F], rhs: &mut [F]) {
+    let n = rhs.len();
+    for i in 1..n {
+        let factor = lower[i] / diagonal[i - 1];
+        diagonal[i] = diagonal[i] - factor * upper[i - 1];
+        rhs[i] = rhs[i] - factor * rhs[i - 1];
+    }
+    rhs[n - 1] = rhs[n - 1] / diagonal[n - 1];
+    for i in (0..n - 1).rev() {
+        rhs[i] = (rhs[i] - upper[i] * rhs[i + 1]) / diagonal[i];
+    }
+}
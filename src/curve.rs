@@ -0,0 +1,104 @@
+//! Parametric curves, and iterators which sample them.
+
+/// Curves which can be evaluated at a parameter `t`.
+///
+/// Curves are parameterized over the range [0..1]: `t == 0.0` is the start of the
+/// curve, and `t == 1.0` is its end.
+pub trait Curve<F> {
+    /// The type of the points along this curve.
+    type Point;
+
+    /// Evaluate the curve at `t`.
+    fn eval(&self, t: F) -> Self::Point;
+}
+
+/// Curves which can be sampled at evenly spaced values of `t`.
+///
+/// It's automatically implemented for all `C: Curve<f64>`.
+pub trait CurveIter: Sized {
+    /// Create an iterator which samples this curve at `steps` evenly spaced values of `t`.
+    ///
+    /// The iterator is half-open: it includes the start of the curve, but not its end,
+    /// per [`LerpIter::lerp_iter`](crate::LerpIter::lerp_iter).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lerp::{CurveIter, LinearBezier};
+    ///
+    /// let points: Vec<f64> = LinearBezier::new(0.0, 8.0).sample_iter(4).collect();
+    /// assert_eq!(points, vec![0.0, 2.0, 4.0, 6.0]);
+    /// ```
+    fn sample_iter(&self, steps: usize) -> CurveIterator<'_, Self>;
+
+    /// Create an iterator which samples this curve at `steps` evenly spaced values of `t`.
+    ///
+    /// The iterator is closed: it includes both the start and the end of the curve,
+    /// per [`LerpIter::lerp_iter_closed`](crate::LerpIter::lerp_iter_closed).
+    ///
+    /// Note when `steps == 1`, the end of the curve is returned instead of its start.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lerp::{CurveIter, LinearBezier};
+    ///
+    /// let points: Vec<f64> = LinearBezier::new(0.0, 8.0).sample_iter_closed(3).collect();
+    /// assert_eq!(points, vec![0.0, 4.0, 8.0]);
+    /// ```
+    fn sample_iter_closed(&self, steps: usize) -> CurveIterator<'_, Self>;
+}
+
+impl<C: Curve<f64>> CurveIter for C {
+    fn sample_iter(&self, steps: usize) -> CurveIterator<'_, C> {
+        CurveIterator::new(self, steps, false)
+    }
+
+    fn sample_iter_closed(&self, steps: usize) -> CurveIterator<'_, C> {
+        CurveIterator::new(self, steps, true)
+    }
+}
+
+/// An iterator which samples a [`Curve`] at evenly spaced values of `t`.
+#[derive(Debug)]
+pub struct CurveIterator<'a, C> {
+    curve: &'a C,
+    steps: usize,
+    current_step: usize,
+    closed: bool,
+}
+
+impl<'a, C> CurveIterator<'a, C> {
+    fn new(curve: &'a C, steps: usize, closed: bool) -> CurveIterator<'a, C> {
+        CurveIterator {
+            curve,
+            steps,
+            current_step: 0,
+            closed,
+        }
+    }
+}
+
+impl<'a, C: Curve<f64>> Iterator for CurveIterator<'a, C> {
+    type Item = C::Point;
+
+    fn next(&mut self) -> Option<C::Point> {
+        if self.current_step >= self.steps {
+            return None;
+        }
+        let t = match (self.closed, self.steps) {
+            (true, 1) => 1.0,
+            (true, steps) => self.current_step as f64 / (steps - 1) as f64,
+            (false, steps) => self.current_step as f64 / steps as f64,
+        };
+        self.current_step += 1;
+        Some(self.curve.eval(t))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.steps.saturating_sub(self.current_step);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, C: Curve<f64>> ExactSizeIterator for CurveIterator<'a, C> {}
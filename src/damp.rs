@@ -0,0 +1,70 @@
+//! Frame-rate-independent exponential smoothing.
+
+use crate::float::constant;
+use crate::Lerp;
+use num_traits::Float;
+
+/// The `t` which moves a value halfway to its target every `half_life`, after `dt`.
+fn half_life_parameter<F: Float>(half_life: F, dt: F) -> F {
+    if half_life <= F::zero() {
+        F::one()
+    } else {
+        decay_parameter(constant::<F>(std::f64::consts::LN_2) / half_life, dt)
+    }
+}
+
+/// The `t` which decays the distance to the target at `rate` per unit time, after `dt`.
+fn decay_parameter<F: Float>(rate: F, dt: F) -> F {
+    // `1 - e^(-rate * dt)`, precisely even when `rate * dt` is tiny
+    -(-rate * dt).exp_m1()
+}
+
+/// Move `current` towards `target`, covering half of the remaining distance every `half_life`.
+///
+/// Calling `current = current.lerp(target, t)` once per frame with a constant `t` moves
+/// faster at higher frame rates. This instead computes `t` from the time `dt` since the
+/// last frame, so that the motion is the same at any frame rate: two frames of `dt`
+/// produce the same result as one frame of `2 * dt`.
+///
+/// `half_life` and `dt` must be measured in the same unit. A zero or negative `half_life`
+/// snaps to `target`.
+///
+/// # Example
+///
+/// ```
+/// use lerp::damp;
+///
+/// assert_eq!(damp(0.0, 8.0, 1.0, 1.0), 4.0);
+/// assert_eq!(damp(0.0, 8.0, 1.0, 2.0), 6.0);
+/// assert_eq!(damp(damp(0.0, 8.0, 1.0, 1.0), 8.0, 1.0, 1.0), 6.0);
+/// ```
+pub fn damp<T, F>(current: T, target: T, half_life: F, dt: F) -> T
+where
+    T: Lerp<F>,
+    F: Float,
+{
+    current.lerp(target, half_life_parameter(half_life, dt))
+}
+
+/// Move `current` towards `target`, decaying the remaining distance exponentially at `rate`
+/// per unit time.
+///
+/// After `dt`, the remaining distance is multiplied by `e^(-rate * dt)`. This is
+/// [`damp`] with a `half_life` of `ln(2) / rate`, for those who think in rates instead.
+/// A rate of zero never moves.
+///
+/// # Example
+///
+/// ```
+/// use lerp::damp_rate;
+///
+/// let value = damp_rate(0.0, 1.0, 2.0, 0.5);
+/// assert!((value - (1.0 - (-1.0_f64).exp())).abs() < 1e-12);
+/// ```
+pub fn damp_rate<T, F>(current: T, target: T, rate: F, dt: F) -> T
+where
+    T: Lerp<F>,
+    F: Float,
+{
+    current.lerp(target, decay_parameter(rate, dt))
+}
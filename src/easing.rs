@@ -0,0 +1,271 @@
+//! Easing functions, which reshape `t` before lerping.
+//!
+//! Each function maps `t` in [0..1] to a progress value, which is `0` at `t == 0` and `1`
+//! at `t == 1`. In between, the progress may accelerate, decelerate, or overshoot.
+//! These are the standard families popularized by Robert Penner, each available as an
+//! `_in` variant (starting slowly), an `_out` variant (ending slowly), and an `_in_out`
+//! variant (both).
+//!
+//! Any `Fn(F) -> F` implements [`Easing`], so these functions, closures, and custom
+//! curves are all interchangeable.
+//!
+//! # Example
+//!
+//! ```
+//! use lerp::easing::{self, ease_lerp};
+//!
+//! assert_eq!(ease_lerp(0.0, 10.0, 0.5, easing::quad_in), 2.5);
+//! assert_eq!(ease_lerp(0.0, 10.0, 0.5, easing::quad_out), 7.5);
+//! assert_eq!(ease_lerp(0.0, 10.0, 0.5, |t: f64| t), 5.0);
+//! ```
+
+use crate::float::constant;
+use crate::Lerp;
+use num_traits::{Float, FloatConst};
+
+/// A function which reshapes the progress `t` of an interpolation.
+///
+/// This is automatically implemented for all `Fn(F) -> F`.
+pub trait Easing<F> {
+    /// Map `t` to eased progress.
+    fn ease(&self, t: F) -> F;
+}
+
+impl<F, E> Easing<F> for E
+where
+    E: Fn(F) -> F,
+{
+    fn ease(&self, t: F) -> F {
+        self(t)
+    }
+}
+
+/// Lerp from `a` to `b`, reshaping `t` with `easing`.
+///
+/// # Example
+///
+/// ```
+/// use lerp::easing::{self, ease_lerp};
+///
+/// assert_eq!(ease_lerp(3.0, 5.0, 1.0, easing::cubic_in_out), 5.0);
+/// ```
+pub fn ease_lerp<T, F, E>(a: T, b: T, t: F, easing: E) -> T
+where
+    T: Lerp<F>,
+    E: Easing<F>,
+{
+    a.lerp(b, easing.ease(t))
+}
+
+/// No easing: progress is equal to `t`.
+pub fn linear<F: Float>(t: F) -> F {
+    t
+}
+
+macro_rules! power_easing {
+    ($($power:literal => $name:literal: $ease_in:ident, $ease_out:ident, $ease_in_out:ident;)*) => {
+        $(
+            #[doc = concat!("Accelerating ", $name, " easing.")]
+            pub fn $ease_in<F: Float>(t: F) -> F {
+                t.powi($power)
+            }
+
+            #[doc = concat!("Decelerating ", $name, " easing.")]
+            pub fn $ease_out<F: Float>(t: F) -> F {
+                F::one() - (F::one() - t).powi($power)
+            }
+
+            #[doc = concat!("Accelerating, then decelerating ", $name, " easing.")]
+            pub fn $ease_in_out<F: Float>(t: F) -> F {
+                let two = constant::<F>(2.0);
+                if t < constant(0.5) {
+                    two.powi($power - 1) * t.powi($power)
+                } else {
+                    F::one() - (two - two * t).powi($power) / two
+                }
+            }
+        )*
+    };
+}
+
+power_easing! {
+    2 => "quadratic": quad_in, quad_out, quad_in_out;
+    3 => "cubic": cubic_in, cubic_out, cubic_in_out;
+    4 => "quartic": quart_in, quart_out, quart_in_out;
+    5 => "quintic": quint_in, quint_out, quint_in_out;
+}
+
+/// Accelerating sinusoidal easing.
+pub fn sine_in<F: Float + FloatConst>(t: F) -> F {
+    F::one() - (t * F::FRAC_PI_2()).cos()
+}
+
+/// Decelerating sinusoidal easing.
+pub fn sine_out<F: Float + FloatConst>(t: F) -> F {
+    (t * F::FRAC_PI_2()).sin()
+}
+
+/// Accelerating, then decelerating sinusoidal easing.
+pub fn sine_in_out<F: Float + FloatConst>(t: F) -> F {
+    (F::one() - (t * F::PI()).cos()) / constant(2.0)
+}
+
+/// Accelerating exponential easing.
+pub fn expo_in<F: Float>(t: F) -> F {
+    if t <= F::zero() {
+        F::zero()
+    } else {
+        constant::<F>(2.0).powf(constant::<F>(10.0) * t - constant(10.0))
+    }
+}
+
+/// Decelerating exponential easing.
+pub fn expo_out<F: Float>(t: F) -> F {
+    if t >= F::one() {
+        F::one()
+    } else {
+        F::one() - constant::<F>(2.0).powf(constant::<F>(-10.0) * t)
+    }
+}
+
+/// Accelerating, then decelerating exponential easing.
+pub fn expo_in_out<F: Float>(t: F) -> F {
+    let two = constant::<F>(2.0);
+    if t <= F::zero() {
+        F::zero()
+    } else if t >= F::one() {
+        F::one()
+    } else if t < constant(0.5) {
+        two.powf(constant::<F>(20.0) * t - constant(10.0)) / two
+    } else {
+        (two - two.powf(constant::<F>(-20.0) * t + constant(10.0))) / two
+    }
+}
+
+/// Accelerating circular easing.
+pub fn circ_in<F: Float>(t: F) -> F {
+    F::one() - (F::one() - t * t).sqrt()
+}
+
+/// Decelerating circular easing.
+pub fn circ_out<F: Float>(t: F) -> F {
+    let t = t - F::one();
+    (F::one() - t * t).sqrt()
+}
+
+/// Accelerating, then decelerating circular easing.
+pub fn circ_in_out<F: Float>(t: F) -> F {
+    let two = constant::<F>(2.0);
+    if t < constant(0.5) {
+        (F::one() - (F::one() - (two * t).powi(2)).sqrt()) / two
+    } else {
+        ((F::one() - (two - two * t).powi(2)).sqrt() + F::one()) / two
+    }
+}
+
+const BACK_OVERSHOOT: f64 = 1.70158;
+
+/// Easing which backs up slightly before accelerating.
+pub fn back_in<F: Float>(t: F) -> F {
+    let c1 = constant::<F>(BACK_OVERSHOOT);
+    (c1 + F::one()) * t.powi(3) - c1 * t.powi(2)
+}
+
+/// Decelerating easing which overshoots slightly before settling.
+pub fn back_out<F: Float>(t: F) -> F {
+    let c1 = constant::<F>(BACK_OVERSHOOT);
+    let t = t - F::one();
+    F::one() + (c1 + F::one()) * t.powi(3) + c1 * t.powi(2)
+}
+
+/// Easing which backs up slightly, then overshoots slightly before settling.
+pub fn back_in_out<F: Float>(t: F) -> F {
+    let two = constant::<F>(2.0);
+    let c2 = constant::<F>(BACK_OVERSHOOT * 1.525);
+    if t < constant(0.5) {
+        let t = two * t;
+        t.powi(2) * ((c2 + F::one()) * t - c2) / two
+    } else {
+        let t = two * t - two;
+        (t.powi(2) * ((c2 + F::one()) * t + c2) + two) / two
+    }
+}
+
+/// Easing which oscillates with growing amplitude, like a plucked spring in reverse.
+pub fn elastic_in<F: Float + FloatConst>(t: F) -> F {
+    if t <= F::zero() {
+        F::zero()
+    } else if t >= F::one() {
+        F::one()
+    } else {
+        let c4 = F::TAU() / constant(3.0);
+        let ten = constant::<F>(10.0);
+        -constant::<F>(2.0).powf(ten * t - ten) * ((ten * t - constant(10.75)) * c4).sin()
+    }
+}
+
+/// Easing which overshoots and oscillates with shrinking amplitude, like a plucked spring.
+pub fn elastic_out<F: Float + FloatConst>(t: F) -> F {
+    if t <= F::zero() {
+        F::zero()
+    } else if t >= F::one() {
+        F::one()
+    } else {
+        let c4 = F::TAU() / constant(3.0);
+        let ten = constant::<F>(10.0);
+        constant::<F>(2.0).powf(-ten * t) * ((ten * t - constant(0.75)) * c4).sin() + F::one()
+    }
+}
+
+/// Easing which oscillates on the way out and on the way in.
+pub fn elastic_in_out<F: Float + FloatConst>(t: F) -> F {
+    if t <= F::zero() {
+        F::zero()
+    } else if t >= F::one() {
+        F::one()
+    } else {
+        let c5 = F::TAU() / constant(4.5);
+        let two = constant::<F>(2.0);
+        let ten = constant::<F>(10.0);
+        let twenty = constant::<F>(20.0);
+        let wave = ((twenty * t - constant(11.125)) * c5).sin();
+        if t < constant(0.5) {
+            -(two.powf(twenty * t - ten) * wave) / two
+        } else {
+            two.powf(-twenty * t + ten) * wave / two + F::one()
+        }
+    }
+}
+
+/// Easing which bounces off the start, like a ball dropped in reverse.
+pub fn bounce_in<F: Float>(t: F) -> F {
+    F::one() - bounce_out(F::one() - t)
+}
+
+/// Easing which bounces to rest at the end, like a dropped ball.
+pub fn bounce_out<F: Float>(t: F) -> F {
+    let n1 = constant::<F>(7.5625);
+    let d1 = constant::<F>(2.75);
+    if t < F::one() / d1 {
+        n1 * t * t
+    } else if t < constant::<F>(2.0) / d1 {
+        let t = t - constant::<F>(1.5) / d1;
+        n1 * t * t + constant(0.75)
+    } else if t < constant::<F>(2.5) / d1 {
+        let t = t - constant::<F>(2.25) / d1;
+        n1 * t * t + constant(0.9375)
+    } else {
+        let t = t - constant::<F>(2.625) / d1;
+        n1 * t * t + constant(0.984375)
+    }
+}
+
+/// Easing which bounces off the start, then bounces to rest at the end.
+pub fn bounce_in_out<F: Float>(t: F) -> F {
+    let two = constant::<F>(2.0);
+    if t < constant(0.5) {
+        (F::one() - bounce_out(F::one() - two * t)) / two
+    } else {
+        (F::one() + bounce_out(two * t - F::one())) / two
+    }
+}
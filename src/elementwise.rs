@@ -0,0 +1,85 @@
+//! Element-wise lerping of arrays and tuples.
+
+use crate::Lerp;
+
+/// Lerp each element of `a` towards the corresponding element of `b`.
+///
+/// # Example
+///
+/// ```
+/// use lerp::lerp_array;
+///
+/// assert_eq!(lerp_array([0.0, 2.0, 4.0], [4.0, 6.0, 0.0], 0.5), [2.0, 4.0, 2.0]);
+/// ```
+pub fn lerp_array<T, F, const N: usize>(a: [T; N], b: [T; N], t: F) -> [T; N]
+where
+    T: Lerp<F>,
+    F: Copy,
+{
+    let mut b = IntoIterator::into_iter(b);
+    a.map(|a| a.lerp(b.next().expect("arrays have equal length"), t))
+}
+
+/// A wrapper which lerps arrays and tuples element-wise.
+///
+/// Arrays and tuples can't implement [`Lerp`] directly: an implementation for them would
+/// conflict with the blanket implementation, should the standard library ever implement
+/// `Add` for them. Wrapping them in `Elementwise` gives them [`Lerp`] for any `F: Copy`
+/// which all of their elements lerp by, and so also [`LerpIter`](crate::LerpIter).
+/// Tuples of up to twelve elements are supported, and nested arrays and tuples must be
+/// wrapped in turn.
+///
+/// # Example
+///
+/// ```
+/// use lerp::{Elementwise, Lerp, LerpIter};
+///
+/// let a = Elementwise((0.0, Elementwise([1.0, 2.0])));
+/// let b = Elementwise((4.0, Elementwise([3.0, 0.0])));
+/// assert_eq!(a.lerp(b, 0.5), Elementwise((2.0, Elementwise([2.0, 1.0]))));
+///
+/// let steps: Vec<_> = Elementwise([0.0, 10.0])
+///     .lerp_iter_closed(Elementwise([2.0, 0.0]), 3)
+///     .map(|step| step.0)
+///     .collect();
+/// assert_eq!(steps, vec![[0.0, 10.0], [1.0, 5.0], [2.0, 0.0]]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Elementwise<T>(pub T);
+
+impl<T, F, const N: usize> Lerp<F> for Elementwise<[T; N]>
+where
+    T: Lerp<F>,
+    F: Copy,
+{
+    fn lerp(self, other: Self, t: F) -> Self {
+        Elementwise(lerp_array(self.0, other.0, t))
+    }
+}
+
+macro_rules! impl_tuple {
+    ($(($($name:ident $index:tt),+))*) => {
+        $(
+            impl<F: Copy, $($name: Lerp<F>),+> Lerp<F> for Elementwise<($($name,)+)> {
+                fn lerp(self, other: Self, t: F) -> Self {
+                    Elementwise(($((self.0).$index.lerp((other.0).$index, t),)+))
+                }
+            }
+        )*
+    };
+}
+
+impl_tuple! {
+    (A 0)
+    (A 0, B 1)
+    (A 0, B 1, C 2)
+    (A 0, B 1, C 2, D 3)
+    (A 0, B 1, C 2, D 3, E 4)
+    (A 0, B 1, C 2, D 3, E 4, G 5)
+    (A 0, B 1, C 2, D 3, E 4, G 5, H 6)
+    (A 0, B 1, C 2, D 3, E 4, G 5, H 6, I 7)
+    (A 0, B 1, C 2, D 3, E 4, G 5, H 6, I 7, J 8)
+    (A 0, B 1, C 2, D 3, E 4, G 5, H 6, I 7, J 8, K 9)
+    (A 0, B 1, C 2, D 3, E 4, G 5, H 6, I 7, J 8, K 9, L 10)
+    (A 0, B 1, C 2, D 3, E 4, G 5, H 6, I 7, J 8, K 9, L 10, M 11)
+}
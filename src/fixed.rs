@@ -0,0 +1,95 @@
+//! Lerping 8- and 16-bit values with fixed-point weights.
+
+use crate::Lerp;
+use num_traits::{Bounded, One, Zero};
+use std::ops::{Add, Mul};
+
+/// A fixed-point lerp parameter, where the full range of `W` spans [0..1].
+///
+/// `Fixed(0)` corresponds to `t == 0.0`, and `Fixed(W::MAX)` corresponds to `t == 1.0`.
+/// This enables blending `u8` values with a `u8` weight, and `u16` values with a
+/// `u16` weight, entirely in integer arithmetic.
+///
+/// Results are rounded to nearest, matching the common
+/// `(a * (255 - t) + b * t + 127) / 255` formulation exactly.
+///
+/// Because the weight can't represent values outside of [0..1], there is no
+/// extrapolation, and [`Lerp::lerp_bounded`] is identical to [`Lerp::lerp`].
+///
+/// # Examples
+///
+/// ```
+/// use lerp::{Fixed, Lerp};
+///
+/// assert_eq!(0_u8.lerp(255, Fixed(0)), 0);
+/// assert_eq!(0_u8.lerp(255, Fixed(128)), 128);
+/// assert_eq!(0_u8.lerp(255, Fixed(255)), 255);
+/// assert_eq!(10_u8.lerp(20, Fixed(128)), 15);
+/// assert_eq!(1000_u16.lerp(0, Fixed(u16::MAX / 2)), 500);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Fixed<W>(pub W);
+
+macro_rules! impl_fixed {
+    ($($weight:ty => $wide:ty),*) => {
+        $(
+            impl Lerp<Fixed<$weight>> for $weight {
+                fn lerp(self, other: Self, t: Fixed<$weight>) -> Self {
+                    let max = <$weight>::MAX as $wide;
+                    let t = t.0 as $wide;
+                    ((self as $wide * (max - t) + other as $wide * t + max / 2) / max) as $weight
+                }
+
+                fn lerp_bounded(self, other: Self, t: Fixed<$weight>) -> Self {
+                    self.lerp(other, t)
+                }
+            }
+
+            /// Saturating addition.
+            impl Add for Fixed<$weight> {
+                type Output = Self;
+
+                fn add(self, other: Self) -> Self {
+                    Fixed(self.0.saturating_add(other.0))
+                }
+            }
+
+            /// Fixed-point multiplication, rounded to nearest.
+            impl Mul for Fixed<$weight> {
+                type Output = Self;
+
+                fn mul(self, other: Self) -> Self {
+                    Fixed((0 as $weight).lerp(self.0, other))
+                }
+            }
+
+            impl Zero for Fixed<$weight> {
+                fn zero() -> Self {
+                    Fixed(0)
+                }
+
+                fn is_zero(&self) -> bool {
+                    self.0 == 0
+                }
+            }
+
+            impl One for Fixed<$weight> {
+                fn one() -> Self {
+                    Fixed(<$weight>::MAX)
+                }
+            }
+
+            impl Bounded for Fixed<$weight> {
+                fn min_value() -> Self {
+                    Fixed(<$weight>::MIN)
+                }
+
+                fn max_value() -> Self {
+                    Fixed(<$weight>::MAX)
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed!(u8 => u32, u16 => u64);
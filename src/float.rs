@@ -0,0 +1,11 @@
+//! Helpers for code generic over float types.
+
+use num_traits::Float;
+
+/// `value`, converted to the float type `F`.
+///
+/// This is for literal constants, which every float type can represent, at least
+/// approximately.
+pub(crate) fn constant<F: Float>(value: f64) -> F {
+    F::from(value).expect("constants are representable as floats")
+}
@@ -0,0 +1,172 @@
+//! Geometric interpolation for scale-like quantities.
+
+use num_traits::Float;
+use std::iter;
+use std::iter::{Chain, Once, Skip};
+
+/// Types which can be interpolated geometrically: `a * (b / a)^t`.
+///
+/// Geometric interpolation is appropriate for quantities where equal ratios are
+/// perceived as equal steps, such as frequencies, zoom levels, gain, and exposure.
+/// Halfway between 1 and 100 is 10, not 50.
+///
+/// Both endpoints must be nonzero and share a sign. Between two negative endpoints,
+/// the magnitude is interpolated geometrically and the sign preserved. Any other
+/// combination of endpoints produces `None`.
+///
+/// It's automatically implemented for all `F: Float`.
+pub trait LerpGeometric: Sized {
+    /// Interpolate and extrapolate geometrically between `self` and `other`.
+    ///
+    /// At `t == 0.0`, the result is equal to `self`.
+    /// At `t == 1.0`, the result is equal to `other`.
+    ///
+    /// `t` is unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lerp::LerpGeometric;
+    ///
+    /// assert_eq!(1.0.lerp_geometric(100.0, 0.5), Some(10.0));
+    /// assert_eq!(100.0.lerp_geometric(1000.0, 2.0), Some(10_000.0));
+    /// assert_eq!((-1.0).lerp_geometric(-100.0, 0.5), Some(-10.0));
+    /// assert_eq!(0.0.lerp_geometric(100.0, 0.5), None);
+    /// assert_eq!((-1.0).lerp_geometric(100.0, 0.5), None);
+    /// ```
+    fn lerp_geometric(self, other: Self, t: Self) -> Option<Self>;
+
+    /// Interpolate geometrically between `self` and `other` per [`LerpGeometric::lerp_geometric`],
+    /// bounding `t` in the inclusive range [0..1].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use lerp::LerpGeometric;
+    /// assert_eq!(1.0.lerp_geometric_bounded(100.0, 2.0), Some(100.0));
+    /// ```
+    fn lerp_geometric_bounded(self, other: Self, t: Self) -> Option<Self>;
+
+    /// Create an iterator which interpolates geometrically from `self` to `other`.
+    ///
+    /// The iterator is half-open: it includes `self`, but not `other`, per
+    /// [`LerpIter::lerp_iter`](crate::LerpIter::lerp_iter).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lerp::LerpGeometric;
+    ///
+    /// let octaves: Vec<_> = 110.0.geom_iter(1760.0, 4).unwrap().collect();
+    /// assert_eq!(octaves, vec![110.0, 220.0, 440.0, 880.0]);
+    /// ```
+    fn geom_iter(self, other: Self, steps: usize) -> Option<GeometricIterator<Self>>;
+
+    /// Create an iterator which interpolates geometrically from `self` to `other`.
+    ///
+    /// The iterator is closed: it returns both `self` and `other`, per
+    /// [`LerpIter::lerp_iter_closed`](crate::LerpIter::lerp_iter_closed).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lerp::LerpGeometric;
+    ///
+    /// let zoom: Vec<_> = 1.0.geom_iter_closed(16.0, 5).unwrap().collect();
+    /// assert_eq!(zoom, vec![1.0, 2.0, 4.0, 8.0, 16.0]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn geom_iter_closed(
+        self,
+        other: Self,
+        steps: usize,
+    ) -> Option<Skip<Chain<GeometricIterator<Self>, Once<Self>>>>;
+}
+
+impl<F: Float> LerpGeometric for F {
+    fn lerp_geometric(self, other: F, t: F) -> Option<F> {
+        let ratio = ratio(self, other)?;
+        Some(if t == F::one() {
+            other
+        } else {
+            self * ratio.powf(t)
+        })
+    }
+
+    fn lerp_geometric_bounded(self, other: F, t: F) -> Option<F> {
+        self.lerp_geometric(other, t.max(F::zero()).min(F::one()))
+    }
+
+    fn geom_iter(self, other: F, steps: usize) -> Option<GeometricIterator<F>> {
+        GeometricIterator::new(self, other, steps)
+    }
+
+    fn geom_iter_closed(
+        self,
+        other: F,
+        steps: usize,
+    ) -> Option<Skip<Chain<GeometricIterator<F>, Once<F>>>> {
+        // see `LerpIter::lerp_iter_closed` for why both branches skip
+        Some(if steps == 0 {
+            GeometricIterator::new(self, other, steps)?
+                .chain(iter::once(other))
+                .skip(1)
+        } else {
+            #[allow(clippy::iter_skip_zero)]
+            GeometricIterator::new(self, other, steps - 1)?
+                .chain(iter::once(other))
+                .skip(0)
+        })
+    }
+}
+
+/// The ratio `b / a`, if geometric interpolation between them is defined.
+fn ratio<F: Float>(a: F, b: F) -> Option<F> {
+    let ratio = b / a;
+    if a.is_zero() || b.is_zero() || ratio <= F::zero() || ratio.is_nan() {
+        None
+    } else {
+        Some(ratio)
+    }
+}
+
+/// An iterator across a geometric range defined by its endpoints and the number of intermediate steps.
+#[derive(Debug, Clone)]
+pub struct GeometricIterator<F> {
+    begin: F,
+    ratio: F,
+    steps: usize,
+    current_step: usize,
+}
+
+impl<F: Float> GeometricIterator<F> {
+    fn new(begin: F, end: F, steps: usize) -> Option<GeometricIterator<F>> {
+        Some(GeometricIterator {
+            begin,
+            ratio: ratio(begin, end)?,
+            steps,
+            current_step: 0,
+        })
+    }
+}
+
+impl<F: Float> Iterator for GeometricIterator<F> {
+    type Item = F;
+
+    fn next(&mut self) -> Option<F> {
+        if self.current_step >= self.steps {
+            None
+        } else {
+            let t = F::from(self.current_step)? / F::from(self.steps)?;
+            self.current_step += 1;
+            Some(self.begin * self.ratio.powf(t))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.steps.saturating_sub(self.current_step);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<F: Float> ExactSizeIterator for GeometricIterator<F> {}
@@ -0,0 +1,142 @@
+//! Helpers matching the behavior of their GLSL namesakes, for porting shader code.
+//!
+//! # Example
+//!
+//! ```
+//! use lerp::glsl::{fract, mix, smoothstep, step};
+//!
+//! assert_eq!(mix(2.0, 4.0, 0.25), 2.5);
+//! assert_eq!(step(0.5, 0.7), 1.0);
+//! assert_eq!(smoothstep(0.0, 2.0, 1.0), 0.5);
+//! assert_eq!(fract(-1.25), 0.75);
+//! ```
+
+use crate::float::constant;
+use crate::{lerp_array, Lerp};
+use num_traits::Float;
+
+/// Linearly blend `x` and `y` by `a`, per [`Lerp::lerp`].
+///
+/// This works for any type which implements [`Lerp`].
+pub fn mix<T: Lerp<F>, F>(x: T, y: T, a: F) -> T {
+    x.lerp(y, a)
+}
+
+/// Linearly blend each component of `x` and `y` by `a`, per [`lerp_array`].
+///
+/// # Example
+///
+/// ```
+/// use lerp::glsl::mix_array;
+///
+/// assert_eq!(mix_array([0.0, 2.0], [4.0, 6.0], 0.5), [2.0, 4.0]);
+/// ```
+pub fn mix_array<T, F, const N: usize>(x: [T; N], y: [T; N], a: F) -> [T; N]
+where
+    T: Lerp<F>,
+    F: Copy,
+{
+    lerp_array(x, y, a)
+}
+
+/// Linearly blend each component of `x` and `y` by the corresponding component of `a`.
+///
+/// # Example
+///
+/// ```
+/// use lerp::glsl::mix_components;
+///
+/// assert_eq!(mix_components([0.0, 2.0], [4.0, 6.0], [0.25, 1.0]), [1.0, 6.0]);
+/// ```
+pub fn mix_components<T, F, const N: usize>(x: [T; N], y: [T; N], a: [F; N]) -> [T; N]
+where
+    T: Lerp<F>,
+{
+    let mut y = IntoIterator::into_iter(y);
+    let mut a = IntoIterator::into_iter(a);
+    x.map(|x| {
+        x.lerp(
+            y.next().expect("arrays have equal length"),
+            a.next().expect("arrays have equal length"),
+        )
+    })
+}
+
+/// `0.0` if `x < edge`; otherwise `1.0`.
+pub fn step<F: Float>(edge: F, x: F) -> F {
+    if x < edge {
+        F::zero()
+    } else {
+        F::one()
+    }
+}
+
+/// Constrain `x` to lie between `min_val` and `max_val`.
+///
+/// This computes `min(max(x, min_val), max_val)`, exactly as GLSL does. Unlike
+/// [`f64::clamp`], it does not panic when `min_val > max_val`; the result is then `max_val`.
+pub fn clamp<F: Float>(x: F, min_val: F, max_val: F) -> F {
+    x.max(min_val).min(max_val)
+}
+
+/// The fractional part of `x`: `x - floor(x)`.
+///
+/// This is always non-negative, even for negative `x`.
+pub fn fract<F: Float>(x: F) -> F {
+    x - x.floor()
+}
+
+/// The position of `x` between `edge0` and `edge1`, bounded to [0..1].
+fn edge_parameter<F: Float>(edge0: F, edge1: F, x: F) -> Option<F> {
+    if edge0 == edge1 {
+        None
+    } else {
+        Some(clamp((x - edge0) / (edge1 - edge0), F::zero(), F::one()))
+    }
+}
+
+/// Smooth Hermite interpolation between `0.0` and `1.0` as `x` moves from `edge0` to `edge1`.
+///
+/// The result has zero slope at both edges: `t * t * (3 - 2 * t)`, where `t` is the
+/// position of `x` between the edges, bounded to [0..1].
+///
+/// GLSL leaves the result undefined when `edge0 >= edge1`. Here, reversed edges produce a
+/// falling curve, and equal edges produce [`step`]`(edge0, x)` instead of dividing by zero.
+///
+/// # Examples
+///
+/// ```
+/// use lerp::glsl::smoothstep;
+///
+/// assert_eq!(smoothstep(1.0, 3.0, 0.0), 0.0);
+/// assert_eq!(smoothstep(1.0, 3.0, 1.5), 0.15625);
+/// assert_eq!(smoothstep(1.0, 3.0, 4.0), 1.0);
+/// assert_eq!(smoothstep(3.0, 1.0, 1.5), 0.84375);
+/// assert_eq!(smoothstep(1.0, 1.0, 1.0), 1.0);
+/// ```
+pub fn smoothstep<F: Float>(edge0: F, edge1: F, x: F) -> F {
+    match edge_parameter(edge0, edge1, x) {
+        Some(t) => t * t * (constant::<F>(3.0) - (t + t)),
+        None => step(edge0, x),
+    }
+}
+
+/// Smoother interpolation between `0.0` and `1.0` as `x` moves from `edge0` to `edge1`.
+///
+/// This is Ken Perlin's improvement on [`smoothstep`]: `6t⁵ - 15t⁴ + 10t³`, which has zero
+/// first and second derivatives at both edges. Edges are handled as in [`smoothstep`].
+///
+/// # Example
+///
+/// ```
+/// use lerp::glsl::smootherstep;
+///
+/// assert_eq!(smootherstep(0.0, 1.0, 0.5), 0.5);
+/// assert_eq!(smootherstep(0.0, 1.0, 0.25), 0.103515625);
+/// ```
+pub fn smootherstep<F: Float>(edge0: F, edge1: F, x: F) -> F {
+    match edge_parameter(edge0, edge1, x) {
+        Some(t) => t * t * t * (t * (t * constant(6.0) - constant(15.0)) + constant(10.0)),
+        None => step(edge0, x),
+    }
+}
@@ -0,0 +1,78 @@
+//! Cubic Hermite interpolation between values with explicit tangents.
+
+use crate::float::constant;
+use num_traits::Float;
+use std::ops::{Add, Mul};
+
+/// Interpolate along the cubic Hermite segment from `p0` to `p1`.
+///
+/// `m0` is the tangent leaving `p0`, and `m1` is the tangent arriving at `p1`. Tangents
+/// are rates of change per unit of `t`: for a segment spanning a duration `d`, such as
+/// keyframes `d` seconds apart, multiply tangents expressed per second by `d`.
+///
+/// At `t == 0.0`, the result is equal to `p0`.
+/// At `t == 1.0`, the result is equal to `p1`.
+///
+/// `t` is unbounded.
+///
+/// This works for any type with the same bounds as the blanket [`Lerp`](crate::Lerp) impl.
+///
+/// # Example
+///
+/// ```
+/// use lerp::hermite;
+///
+/// assert_eq!(hermite(0.0, 0.0, 1.0, 0.0, 0.5), 0.5);
+/// assert_eq!(hermite(0.0, 1.0, 1.0, 1.0, 0.25), 0.25);
+/// ```
+pub fn hermite<T, F>(p0: T, m0: T, p1: T, m1: T, t: F) -> T
+where
+    T: Add<Output = T> + Mul<F, Output = T>,
+    F: Float,
+{
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let two = constant::<F>(2.0);
+    let three = constant::<F>(3.0);
+
+    let h00 = two * t3 - three * t2 + F::one();
+    let h10 = t3 - two * t2 + t;
+    let h01 = three * t2 - two * t3;
+    let h11 = t3 - t2;
+    p0 * h00 + m0 * h10 + p1 * h01 + m1 * h11
+}
+
+/// The derivative with respect to `t` of the cubic Hermite segment from `p0` to `p1`,
+/// per [`hermite`].
+///
+/// At `t == 0.0`, the result is equal to `m0`.
+/// At `t == 1.0`, the result is equal to `m1`.
+///
+/// This is a rate of change per unit of `t`: for a segment spanning a duration `d`,
+/// divide it by `d` to get the velocity per unit of time.
+///
+/// # Example
+///
+/// ```
+/// use lerp::hermite_derivative;
+///
+/// assert_eq!(hermite_derivative(0.0, 2.0, 1.0, 3.0, 0.0), 2.0);
+/// assert_eq!(hermite_derivative(0.0, 0.0, 1.0, 0.0, 0.5), 1.5);
+/// ```
+pub fn hermite_derivative<T, F>(p0: T, m0: T, p1: T, m1: T, t: F) -> T
+where
+    T: Add<Output = T> + Mul<F, Output = T>,
+    F: Float,
+{
+    let t2 = t * t;
+    let two = constant::<F>(2.0);
+    let three = constant::<F>(3.0);
+    let four = constant::<F>(4.0);
+    let six = constant::<F>(6.0);
+
+    let h00 = six * t2 - six * t;
+    let h10 = three * t2 - four * t + F::one();
+    let h01 = six * t - six * t2;
+    let h11 = three * t2 - two * t;
+    p0 * h00 + m0 * h10 + p1 * h01 + m1 * h11
+}
@@ -0,0 +1,232 @@
+//! Lerping primitive integers with an explicit rounding mode.
+
+use crate::Lerp;
+use num_traits::{Float, One, Zero};
+use std::convert::TryFrom;
+use std::ops::{Add, Mul};
+
+#[derive(Clone, Copy)]
+enum Rounding {
+    Floor,
+    Ceil,
+    NearestEven,
+    TowardZero,
+}
+
+/// Where the exact result of a lerp lies between two consecutive integers.
+#[derive(Clone, Copy, PartialEq)]
+enum Fraction {
+    Zero,
+    BelowHalf,
+    Half,
+    AboveHalf,
+}
+
+impl Fraction {
+    /// The fraction `1 - self`, for the same value approached from the other side.
+    fn complement(self) -> Fraction {
+        match self {
+            Fraction::BelowHalf => Fraction::AboveHalf,
+            Fraction::AboveHalf => Fraction::BelowHalf,
+            fraction => fraction,
+        }
+    }
+}
+
+/// Lerp between `a` and `b` in `i128`, which is wide enough that no
+/// intermediate value can overflow for any primitive integer up to 64 bits.
+///
+/// The float `t` is decomposed into its integer mantissa and exponent, so that
+/// the product `(b - a) * t` is computed exactly before rounding.
+fn lerp_integer<F: Float>(a: i128, b: i128, t: F, rounding: Rounding) -> i128 {
+    if t.is_nan() || t.is_zero() || a == b {
+        return a;
+    }
+    if t.is_one() {
+        return b;
+    }
+
+    let negative = (b < a) != (t < F::zero());
+    if t.is_infinite() {
+        return if negative { i128::MIN } else { i128::MAX };
+    }
+
+    // |b - a| < 2^65 and a mantissa has at most 53 bits, so the product fits in 118 bits
+    let (mantissa, exponent, _) = t.integer_decode();
+    let product = (b - a).unsigned_abs() * u128::from(mantissa);
+    let (whole, fraction) = if exponent >= 0 {
+        let shift = exponent as u32;
+        let whole = if shift < product.leading_zeros() {
+            product << shift
+        } else {
+            u128::MAX
+        };
+        (whole, Fraction::Zero)
+    } else {
+        let shift = exponent.unsigned_abs() as u32;
+        if shift >= 128 {
+            // the product is below 2^118, so this is less than one half
+            let fraction = if product == 0 {
+                Fraction::Zero
+            } else {
+                Fraction::BelowHalf
+            };
+            (0, fraction)
+        } else {
+            let remainder = product & ((1 << shift) - 1);
+            let half = 1 << (shift - 1);
+            let fraction = match remainder {
+                0 => Fraction::Zero,
+                r if r < half => Fraction::BelowHalf,
+                r if r == half => Fraction::Half,
+                _ => Fraction::AboveHalf,
+            };
+            (product >> shift, fraction)
+        }
+    };
+
+    // the floor of the signed product, and the fraction above it
+    let whole = i128::try_from(whole).unwrap_or(i128::MAX);
+    let (whole, fraction) = if !negative {
+        (whole, fraction)
+    } else if fraction == Fraction::Zero {
+        (-whole, fraction)
+    } else {
+        (-whole - 1, fraction.complement())
+    };
+    let base = a.saturating_add(whole);
+
+    let round_up = match rounding {
+        Rounding::Floor => false,
+        Rounding::Ceil => fraction != Fraction::Zero,
+        Rounding::NearestEven => {
+            fraction == Fraction::AboveHalf || (fraction == Fraction::Half && base % 2 != 0)
+        }
+        Rounding::TowardZero => fraction != Fraction::Zero && base < 0,
+    };
+
+    if round_up {
+        base.saturating_add(1)
+    } else {
+        base
+    }
+}
+
+macro_rules! rounding_parameter {
+    ($($(#[$attr:meta])* $name:ident => $rounding:ident;)*) => {
+        $(
+            $(#[$attr])*
+            #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+            pub struct $name<F>(pub F);
+
+            impl<F: Add<Output = F>> Add for $name<F> {
+                type Output = Self;
+
+                fn add(self, other: Self) -> Self {
+                    $name(self.0 + other.0)
+                }
+            }
+
+            impl<F: Mul<Output = F>> Mul for $name<F> {
+                type Output = Self;
+
+                fn mul(self, other: Self) -> Self {
+                    $name(self.0 * other.0)
+                }
+            }
+
+            impl<F: Zero> Zero for $name<F> {
+                fn zero() -> Self {
+                    $name(F::zero())
+                }
+
+                fn is_zero(&self) -> bool {
+                    self.0.is_zero()
+                }
+            }
+
+            impl<F: One> One for $name<F> {
+                fn one() -> Self {
+                    $name(F::one())
+                }
+            }
+
+            impl_integer_lerp!($name => $rounding; i8 i16 i32 i64 isize u8 u16 u32 u64 usize);
+        )*
+    };
+}
+
+macro_rules! impl_integer_lerp {
+    ($name:ident => $rounding:ident; $($int:ty)*) => {
+        $(
+            impl<F: Float> Lerp<$name<F>> for $int {
+                fn lerp(self, other: Self, t: $name<F>) -> Self {
+                    let value = lerp_integer(self as i128, other as i128, t.0, Rounding::$rounding);
+                    value.max(<$int>::MIN as i128).min(<$int>::MAX as i128) as $int
+                }
+            }
+        )*
+    };
+}
+
+rounding_parameter! {
+    /// A lerp parameter which rounds integer results toward negative infinity.
+    ///
+    /// Wrapping `t` in a rounding mode enables lerping primitive integers up to 64 bits wide.
+    /// The rounding mode is applied to the exact value of `a + (b - a) * t`, computed without
+    /// overflow or loss of precision, and results outside of the integer's range saturate at
+    /// its `MIN` or `MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lerp::{Floor, Lerp};
+    ///
+    /// assert_eq!(0_i32.lerp(5, Floor(0.5)), 2);
+    /// assert_eq!(0_i32.lerp(-5, Floor(0.5)), -3);
+    /// assert_eq!(i64::MIN.lerp(i64::MAX, Floor(2.0)), i64::MAX);
+    /// assert_eq!(10_u8.lerp_bounded(20, Floor(-1.0)), 10);
+    /// ```
+    Floor => Floor;
+    /// A lerp parameter which rounds integer results toward positive infinity.
+    ///
+    /// See [`Floor`] for the guarantees made when lerping integers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lerp::{Ceil, Lerp};
+    ///
+    /// assert_eq!(0_i32.lerp(5, Ceil(0.5)), 3);
+    /// assert_eq!(0_i32.lerp(-5, Ceil(0.5)), -2);
+    /// ```
+    Ceil => Ceil;
+    /// A lerp parameter which rounds integer results to the nearest integer, with ties
+    /// rounding to the even neighbor.
+    ///
+    /// See [`Floor`] for the guarantees made when lerping integers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lerp::{Lerp, NearestEven};
+    ///
+    /// assert_eq!(0_i32.lerp(5, NearestEven(0.5)), 2);
+    /// assert_eq!(0_i32.lerp(7, NearestEven(0.5)), 4);
+    /// assert_eq!(0_i32.lerp(10, NearestEven(0.33)), 3);
+    /// ```
+    NearestEven => NearestEven;
+    /// A lerp parameter which rounds integer results toward zero.
+    ///
+    /// See [`Floor`] for the guarantees made when lerping integers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lerp::{Lerp, TowardZero};
+    ///
+    /// assert_eq!(0_i32.lerp(5, TowardZero(0.5)), 2);
+    /// assert_eq!(0_i32.lerp(-5, TowardZero(0.5)), -2);
+    /// ```
+    TowardZero => TowardZero;
+}
@@ -0,0 +1,179 @@
+//! Piecewise-linear interpolation over tables of knots.
+
+use crate::slice::check_lengths;
+use crate::{Lerp, SplineError};
+use num_traits::Float;
+
+/// What to produce when interpolating outside the range of a table's knots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Extrapolation<T> {
+    /// Produce the value of the nearest knot, as [`Lerp::lerp_bounded`] does.
+    Clamp,
+    /// Extend the nearest interval's line, as [`Lerp::lerp`] does.
+    ///
+    /// Where the end knot's x is repeated, the line runs to the nearest knot with a
+    /// different x. Where there is no such knot, this produces the end knot's value.
+    Extend,
+    /// Produce this constant value.
+    Fill(T),
+}
+
+impl<T: Copy> Extrapolation<T> {
+    /// Apply this behavior to `x` beyond the end knot `(x0, y0)`, given its neighbor `(x1, y1)`.
+    fn apply<F>(self, x: F, (x0, y0): (F, T), neighbor: Option<(F, T)>) -> T
+    where
+        T: Lerp<F>,
+        F: Float,
+    {
+        match (self, neighbor) {
+            (Extrapolation::Fill(value), _) => value,
+            (Extrapolation::Extend, Some((x1, y1))) => y0.lerp(y1, (x - x0) / (x1 - x0)),
+            _ => y0,
+        }
+    }
+}
+
+/// Interpolate linearly through the knots `(xs[i], ys[i])` at `x`, clamping to the
+/// first and last knots outside their range.
+///
+/// This is `interp` as numpy defines it. It works for any `T: Lerp<F> + Copy`.
+///
+/// `xs` must be sorted in increasing order; this is not checked, and the result is
+/// unspecified otherwise. A value which appears more than once in `xs` produces a
+/// discontinuity: `x` exactly equal to it takes the `y` of its last appearance.
+///
+/// Each lookup is a binary search, taking `O(log n)` time.
+///
+/// Returns an error if `xs` and `ys` have different lengths or are empty.
+///
+/// # Example
+///
+/// ```
+/// use lerp::interp;
+///
+/// let xs = [0.0, 1.0, 3.0];
+/// let ys = [10.0, 20.0, 0.0];
+/// assert_eq!(interp(0.5, &xs, &ys), Ok(15.0));
+/// assert_eq!(interp(2.5, &xs, &ys), Ok(5.0));
+/// assert_eq!(interp(-1.0, &xs, &ys), Ok(10.0));
+/// assert_eq!(interp(4.0, &xs, &ys), Ok(0.0));
+/// ```
+pub fn interp<T, F>(x: F, xs: &[F], ys: &[T]) -> Result<T, SplineError>
+where
+    T: Lerp<F> + Copy,
+    F: Float,
+{
+    interp_with(x, xs, ys, Extrapolation::Clamp, Extrapolation::Clamp)
+}
+
+/// Interpolate linearly through the knots `(xs[i], ys[i])` at `x`, per [`interp`],
+/// choosing what to produce to the `left` of the first knot and the `right` of the last.
+///
+/// # Example
+///
+/// ```
+/// use lerp::{interp_with, Extrapolation};
+///
+/// let xs = [0.0, 1.0, 3.0];
+/// let ys = [10.0, 20.0, 0.0];
+/// let left = Extrapolation::Extend;
+/// let right = Extrapolation::Fill(-1.0);
+/// assert_eq!(interp_with(-1.0, &xs, &ys, left, right), Ok(0.0));
+/// assert_eq!(interp_with(4.0, &xs, &ys, left, right), Ok(-1.0));
+/// ```
+pub fn interp_with<T, F>(
+    x: F,
+    xs: &[F],
+    ys: &[T],
+    left: Extrapolation<T>,
+    right: Extrapolation<T>,
+) -> Result<T, SplineError>
+where
+    T: Lerp<F> + Copy,
+    F: Float,
+{
+    check_table(xs, ys)?;
+    Ok(lookup(x, xs, ys, left, right))
+}
+
+/// Interpolate linearly through the knots `(xs[i], ys[i])` at each of `x`, per
+/// [`interp_with`], writing the results to `out`.
+///
+/// `x` and `out` must have the same length; otherwise, `out` is left untouched.
+///
+/// # Example
+///
+/// ```
+/// use lerp::{interp_slice, Extrapolation};
+///
+/// let xs = [0.0, 1.0, 3.0];
+/// let ys = [10.0, 20.0, 0.0];
+/// let mut out = [0.0; 4];
+/// interp_slice(
+///     &[-1.0, 0.5, 2.0, 4.0],
+///     &xs,
+///     &ys,
+///     Extrapolation::Clamp,
+///     Extrapolation::Extend,
+///     &mut out,
+/// )
+/// .unwrap();
+/// assert_eq!(out, [10.0, 15.0, 10.0, -10.0]);
+/// ```
+pub fn interp_slice<T, F>(
+    x: &[F],
+    xs: &[F],
+    ys: &[T],
+    left: Extrapolation<T>,
+    right: Extrapolation<T>,
+    out: &mut [T],
+) -> Result<(), SplineError>
+where
+    T: Lerp<F> + Copy,
+    F: Float,
+{
+    check_table(xs, ys)?;
+    check_lengths(x.len(), &[out.len()])?;
+    for (out, &x) in out.iter_mut().zip(x) {
+        *out = lookup(x, xs, ys, left, right);
+    }
+    Ok(())
+}
+
+fn check_table<T, F>(xs: &[F], ys: &[T]) -> Result<(), SplineError> {
+    check_lengths(xs.len(), &[ys.len()])?;
+    if xs.is_empty() {
+        Err(SplineError::TooFewPoints)
+    } else {
+        Ok(())
+    }
+}
+
+/// `xs` and `ys` must be non-empty and have equal lengths.
+fn lookup<T, F>(x: F, xs: &[F], ys: &[T], left: Extrapolation<T>, right: Extrapolation<T>) -> T
+where
+    T: Lerp<F> + Copy,
+    F: Float,
+{
+    let last = xs.len() - 1;
+    let knot = |i: usize| (xs[i], ys[i]);
+    if x < xs[0] {
+        // where the first x is repeated, its last appearance is the end knot, as it is
+        // exactly at that x
+        let end = xs.partition_point(|&knot| knot <= xs[0]) - 1;
+        return left.apply(x, knot(end), (end < last).then(|| knot(end + 1)));
+    }
+    if x > xs[last] {
+        // the neighbor is the nearest knot with a different x
+        let neighbor = xs.partition_point(|&knot| knot < xs[last]).checked_sub(1);
+        return right.apply(x, knot(last), neighbor.map(knot));
+    }
+
+    // the last knot at or before `x`
+    let i = xs.partition_point(|&knot| knot <= x).saturating_sub(1);
+    if i >= last {
+        ys[last]
+    } else {
+        ys[i].lerp(ys[i + 1], (x - xs[i]) / (xs[i + 1] - xs[i]))
+    }
+}
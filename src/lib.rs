@@ -10,6 +10,58 @@ use std::ops::{Add, Mul};
 
 pub use num_traits;
 
+pub mod easing;
+pub mod glsl;
+
+mod akima;
+mod batch;
+mod bezier;
+mod catmull_rom;
+mod cubic_spline;
+mod curve;
+mod damp;
+mod elementwise;
+mod fixed;
+mod float;
+mod geometric;
+mod hermite;
+mod integer;
+mod interp;
+mod monotone;
+mod periodic;
+mod piecewise;
+mod remap;
+mod rotation;
+mod slice;
+mod strategy;
+mod timeline;
+mod track;
+mod tween;
+
+pub use akima::AkimaSpline;
+pub use batch::LerpBatch;
+pub use bezier::{Bezier, CubicBezier, LinearBezier, QuadBezier};
+pub use catmull_rom::{CatmullRom, CatmullRomKind, Distance};
+pub use cubic_spline::{CubicSpline, SplineBoundary};
+pub use curve::{Curve, CurveIter, CurveIterator};
+pub use damp::{damp, damp_rate};
+pub use elementwise::{lerp_array, Elementwise};
+pub use fixed::Fixed;
+pub use geometric::{GeometricIterator, LerpGeometric};
+pub use hermite::{hermite, hermite_derivative};
+pub use integer::{Ceil, Floor, NearestEven, TowardZero};
+pub use interp::{interp, interp_slice, interp_with, Extrapolation};
+pub use monotone::MonotoneCubic;
+pub use periodic::{lerp_periodic, Degrees, Periodic, Radians};
+pub use piecewise::SplineError;
+pub use remap::{remap, remap_bounded, Remap};
+pub use rotation::{Quaternion, Slerp, Vector3};
+pub use slice::{lerp_slice, lerp_slice_in_place, lerp_vec, LengthMismatch};
+pub use strategy::{LerpStrategy, WithStrategy};
+pub use timeline::{Animation, Position, Timeline};
+pub use track::{Key, KeyMode, Track, TrackSampler, WrapMode};
+pub use tween::{Repeat, Tween};
+
 /// Types which are amenable to linear interpolation and extrapolation.
 ///
 /// This is mainly intended to be useful for complex
@@ -18,6 +70,14 @@ pub use num_traits;
 ///
 /// It's automatically implemented
 /// for all `T: Add<Output = T> + Mul<F, Output = T>`.
+///
+/// Primitive integers implement it when `t` is wrapped in a rounding mode,
+/// such as [`Floor`], and `u8` and `u16` also implement it for a [`Fixed`] weight.
+///
+/// Arrays and tuples do not implement it: an implementation for them would conflict with
+/// the blanket implementation, should the standard library ever implement `Add` for them.
+/// Wrap them in [`Elementwise`], or use [`lerp_array`], instead. Array and tuple fields
+/// are also supported by the `Lerp` derive macro.
 pub trait Lerp<F> {
     /// Interpolate and extrapolate between `self` and `other` using `t` as the parameter.
     ///
@@ -109,6 +169,31 @@ pub trait Lerp<F> {
     {
         *self = self.lerp_bounded(other, t);
     }
+
+    /// Move towards `other` over the time `dt`, covering half of the remaining distance
+    /// every `half_life`, per [`damp`].
+    ///
+    /// Unlike calling [`Lerp::lerp_to`] with a constant `t` every frame, this behaves the
+    /// same at any frame rate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lerp::Lerp;
+    ///
+    /// let mut value = 0.0;
+    /// value.lerp_to_dt(8.0, 1.0, 1.0);
+    /// assert_eq!(value, 4.0);
+    /// value.lerp_to_dt(8.0, 1.0, 1.0);
+    /// assert_eq!(value, 6.0);
+    /// ```
+    fn lerp_to_dt(&mut self, other: Self, half_life: F, dt: F)
+    where
+        Self: Sized + Copy,
+        F: Float,
+    {
+        *self = damp(*self, other, half_life, dt);
+    }
 }
 
 /// Types which can construct a lerping iterator from one point to another
@@ -201,6 +286,152 @@ where
     }
 }
 
+/// Types which can be linearly interpolated and extrapolated by reference.
+///
+/// This is useful for heap-backed and otherwise non-`Copy` types, such as large matrices
+/// or arbitrary-precision numbers, which would otherwise need to be cloned to call [`Lerp::lerp`].
+///
+/// It's automatically implemented
+/// for all `T` where `&T: Add<&T, Output = T> + Mul<F, Output = T>`.
+pub trait LerpRef<F> {
+    /// Interpolate and extrapolate between `self` and `other` using `t` as the parameter,
+    /// per [`Lerp::lerp`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lerp::LerpRef;
+    /// use std::ops::{Add, Mul};
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Samples(Vec<f64>);
+    ///
+    /// impl<'a> Add for &'a Samples {
+    ///     type Output = Samples;
+    ///
+    ///     fn add(self, other: Self) -> Samples {
+    ///         Samples(self.0.iter().zip(&other.0).map(|(a, b)| a + b).collect())
+    ///     }
+    /// }
+    ///
+    /// impl<'a> Mul<f64> for &'a Samples {
+    ///     type Output = Samples;
+    ///
+    ///     fn mul(self, t: f64) -> Samples {
+    ///         Samples(self.0.iter().map(|a| a * t).collect())
+    ///     }
+    /// }
+    ///
+    /// let a = Samples(vec![0.0, 2.0]);
+    /// let b = Samples(vec![4.0, 6.0]);
+    /// assert_eq!(a.lerp_ref(&b, 0.5), Samples(vec![2.0, 4.0]));
+    /// ```
+    fn lerp_ref(&self, other: &Self, t: F) -> Self;
+
+    /// Mutating variant of [`LerpRef::lerp_ref`].
+    ///
+    /// This does not require `Self: Copy`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lerp::LerpRef;
+    ///
+    /// let mut value = 3.0;
+    /// value.lerp_assign(&5.0, 0.5);
+    /// assert_eq!(value, 4.0);
+    /// ```
+    fn lerp_assign(&mut self, other: &Self, t: F)
+    where
+        Self: Sized,
+    {
+        *self = self.lerp_ref(other, t);
+    }
+}
+
+/// Default, generic implementation of LerpRef.
+///
+/// This computes the same formula as the default implementation of [`Lerp`].
+impl<T, F> LerpRef<F> for T
+where
+    for<'a> &'a T: Add<&'a T, Output = T> + Mul<F, Output = T>,
+    F: Float,
+{
+    fn lerp_ref(&self, other: &T, t: F) -> T {
+        &(self * (F::one() - t)) + &(other * t)
+    }
+}
+
+/// Types which can recover the interpolation parameter from a value between two endpoints.
+///
+/// This is the inverse of [`Lerp::lerp`]: for any `t`, `a.inverse_lerp(b, a.lerp(b, t))`
+/// is approximately `t`.
+///
+/// It's automatically implemented for all `F: Float`.
+pub trait InverseLerp<F> {
+    /// Compute the parameter `t` at which `value` lies on the line from `self` to `other`.
+    ///
+    /// At `value == self`, the result is `0.0`.
+    /// At `value == other`, the result is `1.0`.
+    ///
+    /// The result is unbounded: values outside the range produce `t` outside `[0..1]`.
+    ///
+    /// Returns `None` when `self == other`, as every `t` would then be equally valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lerp::InverseLerp;
+    ///
+    /// assert_eq!(3.0.inverse_lerp(5.0, 4.0), Some(0.5));
+    /// assert_eq!(3.0.inverse_lerp(5.0, 7.0), Some(2.0));
+    /// assert_eq!(5.0.inverse_lerp(3.0, 4.0), Some(0.5));
+    /// ```
+    ///
+    /// Degenerate ranges:
+    ///
+    /// ```
+    /// # use lerp::InverseLerp;
+    /// assert_eq!(3.0.inverse_lerp(3.0, 4.0), None);
+    /// ```
+    fn inverse_lerp(self, other: Self, value: Self) -> Option<F>;
+
+    /// Compute the parameter per [`InverseLerp::inverse_lerp`], bounding the result
+    /// in the inclusive range [0..1].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use lerp::InverseLerp;
+    /// assert_eq!(3.0.inverse_lerp_bounded(5.0, 7.0), Some(1.0));
+    /// assert_eq!(3.0.inverse_lerp_bounded(5.0, 1.0), Some(0.0));
+    /// ```
+    fn inverse_lerp_bounded(self, other: Self, value: Self) -> Option<F>
+    where
+        Self: Sized,
+        F: PartialOrd + Zero + One,
+    {
+        self.inverse_lerp(other, value).map(|t| match t {
+            t if t < F::zero() => F::zero(),
+            t if t > F::one() => F::one(),
+            t => t,
+        })
+    }
+}
+
+impl<F> InverseLerp<F> for F
+where
+    F: Float,
+{
+    fn inverse_lerp(self, other: F, value: F) -> Option<F> {
+        if self == other {
+            None
+        } else {
+            Some((value - self) / (other - self))
+        }
+    }
+}
+
 impl<T> LerpIter for T
 where
     T: Lerp<f64> + Sized,
@@ -0,0 +1,95 @@
+//! Shape-preserving monotone cubic interpolation.
+
+use crate::float::constant;
+use crate::piecewise::{check_knots, piecewise_cubic_methods, secants, PiecewiseCubic};
+use crate::SplineError;
+use num_traits::Float;
+
+/// A monotone piecewise cubic interpolator through a table of `(x, y)` knots, also
+/// known as PCHIP.
+///
+/// Between any two knots, the curve never leaves the range of their `y` values, so it
+/// never overshoots: where the data is monotone, so is the curve, and each local extremum
+/// of the data is a flat extremum of the curve. The cost is that only the first derivative
+/// is continuous, not the second as in a [`CubicSpline`](crate::CubicSpline).
+///
+/// Slopes follow Fritsch and Carlson's method, with the weighted harmonic mean of
+/// Fritsch and Butland at interior knots, and the shape-preserving three-point formula
+/// at the ends.
+///
+/// # Example
+///
+/// ```
+/// use lerp::MonotoneCubic;
+///
+/// let xs = vec![0.0, 1.0, 2.0, 3.0];
+/// let ys = vec![0.0, 0.0, 1.0, 1.0];
+/// let curve = MonotoneCubic::new(xs, ys).unwrap();
+///
+/// assert_eq!(curve.eval(1.5), 0.5);
+/// assert!((0..=30).all(|i| {
+///     let y = curve.eval(i as f64 / 10.0);
+///     0.0 <= y && y <= 1.0
+/// }));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonotoneCubic<F> {
+    curve: PiecewiseCubic<F>,
+}
+
+impl<F: Float> MonotoneCubic<F> {
+    /// Fit a monotone cubic through the knots `(xs[i], ys[i])`.
+    ///
+    /// `xs` must be strictly increasing, and there must be at least two knots.
+    pub fn new(xs: Vec<F>, ys: Vec<F>) -> Result<MonotoneCubic<F>, SplineError> {
+        check_knots(&xs, &ys)?;
+        let slopes = slopes(&xs, &ys);
+        Ok(MonotoneCubic {
+            curve: PiecewiseCubic::new(xs, ys, slopes),
+        })
+    }
+
+    piecewise_cubic_methods!();
+}
+
+/// Whether `a` and `b` are both strictly positive or both strictly negative.
+fn same_sign<F: Float>(a: F, b: F) -> bool {
+    (a > F::zero() && b > F::zero()) || (a < F::zero() && b < F::zero())
+}
+
+fn slopes<F: Float>(xs: &[F], ys: &[F]) -> Vec<F> {
+    let n = xs.len();
+    let secant = secants(xs, ys);
+    if n == 2 {
+        return vec![secant[0]; 2];
+    }
+    let dx: Vec<F> = xs.windows(2).map(|pair| pair[1] - pair[0]).collect();
+    let two = constant::<F>(2.0);
+
+    let mut slopes = vec![F::zero(); n];
+    for i in 1..n - 1 {
+        // the slope is zero at local extrema, and wherever the data is flat on either side
+        if same_sign(secant[i - 1], secant[i]) {
+            let w1 = two * dx[i] + dx[i - 1];
+            let w2 = dx[i] + two * dx[i - 1];
+            slopes[i] = (w1 + w2) / (w1 / secant[i - 1] + w2 / secant[i]);
+        }
+    }
+    slopes[0] = end_slope(dx[0], dx[1], secant[0], secant[1]);
+    slopes[n - 1] = end_slope(dx[n - 2], dx[n - 3], secant[n - 2], secant[n - 3]);
+    slopes
+}
+
+/// The slope at an end knot, from the widths and secants of the nearest two intervals,
+/// limited so that it cannot cause an overshoot.
+fn end_slope<F: Float>(h0: F, h1: F, secant0: F, secant1: F) -> F {
+    let slope = ((h0 + h0 + h1) * secant0 - h0 * secant1) / (h0 + h1);
+    let three = constant::<F>(3.0);
+    if !same_sign(slope, secant0) {
+        F::zero()
+    } else if !same_sign(secant0, secant1) && slope.abs() > three * secant0.abs() {
+        three * secant0
+    } else {
+        slope
+    }
+}
@@ -0,0 +1,139 @@
+//! Shortest-path lerping for angles and other periodic values.
+
+use crate::float::constant;
+use crate::Lerp;
+use num_traits::{Float, FloatConst};
+
+/// Wrap `value` into the half-open range [0..period).
+fn wrap<F: Float>(value: F, period: F) -> F {
+    let wrapped = value % period;
+    let wrapped = if wrapped < F::zero() {
+        wrapped + period
+    } else {
+        wrapped
+    };
+    // adding the period to a tiny negative remainder can round up to the period itself
+    if wrapped >= period {
+        F::zero()
+    } else {
+        wrapped
+    }
+}
+
+/// Interpolate and extrapolate between `a` and `b`, which repeat every `period`,
+/// taking the shortest path between them.
+///
+/// The result is wrapped into the range [0..period). When `a` and `b` are exactly
+/// half a period apart, the path runs in the positive direction.
+///
+/// # Examples
+///
+/// ```
+/// use lerp::lerp_periodic;
+///
+/// // degrees
+/// assert_eq!(lerp_periodic(350.0, 10.0, 0.5, 360.0), 0.0);
+/// assert_eq!(lerp_periodic(350.0, 10.0, 0.25, 360.0), 355.0);
+/// // time of day
+/// assert_eq!(lerp_periodic(22.0, 4.0, 0.5, 24.0), 1.0);
+/// ```
+pub fn lerp_periodic<F: Float>(a: F, b: F, t: F, period: F) -> F {
+    let half = period / constant(2.0);
+    let mut delta = wrap(b - a, period);
+    if delta > half {
+        delta = delta - period;
+    }
+    wrap(a + delta * t, period)
+}
+
+/// A value which repeats every `period`, such as a hue or a time of day.
+///
+/// Lerping takes the shortest path between two values, per [`lerp_periodic`],
+/// using the period of `self`.
+///
+/// Since this implements `Lerp`, it also works with [`LerpIter`](crate::LerpIter)
+/// and [`Lerp::lerp_bounded`].
+///
+/// # Example
+///
+/// ```
+/// use lerp::{Lerp, LerpIter, Periodic};
+///
+/// let hue = Periodic::new(0.9, 1.0);
+/// assert_eq!(hue.lerp(Periodic::new(0.1, 1.0), 0.5).value, 0.0);
+///
+/// let hues: Vec<_> = hue
+///     .lerp_iter_closed(Periodic::new(0.1, 1.0), 3)
+///     .map(|hue| hue.value)
+///     .collect();
+/// assert_eq!(hues, vec![0.9, 0.0, 0.1]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Periodic<F> {
+    /// The value.
+    pub value: F,
+    /// The interval after which the value repeats.
+    pub period: F,
+}
+
+impl<F> Periodic<F> {
+    /// Create a value which repeats every `period`.
+    pub fn new(value: F, period: F) -> Periodic<F> {
+        Periodic { value, period }
+    }
+}
+
+impl<F: Float> Lerp<F> for Periodic<F> {
+    fn lerp(self, other: Self, t: F) -> Self {
+        Periodic {
+            value: lerp_periodic(self.value, other.value, t, self.period),
+            period: self.period,
+        }
+    }
+}
+
+/// An angle in radians, which lerps along the shortest arc.
+///
+/// Results are wrapped into the range [0..2π).
+///
+/// # Example
+///
+/// ```
+/// use lerp::{Lerp, Radians};
+/// use std::f64::consts::PI;
+///
+/// let angle = Radians(1.5 * PI).lerp(Radians(0.5 * PI), 0.25);
+/// assert!((angle.0 - 1.75 * PI).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Radians<F>(pub F);
+
+impl<F: Float + FloatConst> Lerp<F> for Radians<F> {
+    fn lerp(self, other: Self, t: F) -> Self {
+        Radians(lerp_periodic(self.0, other.0, t, F::TAU()))
+    }
+}
+
+/// An angle in degrees, which lerps along the shortest arc.
+///
+/// Results are wrapped into the range [0..360).
+///
+/// # Example
+///
+/// ```
+/// use lerp::{Degrees, Lerp, LerpIter};
+///
+/// assert_eq!(Degrees(350.0).lerp(Degrees(10.0), 0.75), Degrees(5.0));
+/// assert_eq!(Degrees(350.0).lerp_bounded(Degrees(10.0), 2.0), Degrees(10.0));
+///
+/// let sweep: Vec<_> = Degrees(350.0_f64).lerp_iter(Degrees(10.0), 4).collect();
+/// assert_eq!(sweep, vec![Degrees(350.0), Degrees(355.0), Degrees(0.0), Degrees(5.0)]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Degrees<F>(pub F);
+
+impl<F: Float> Lerp<F> for Degrees<F> {
+    fn lerp(self, other: Self, t: F) -> Self {
+        Degrees(lerp_periodic(self.0, other.0, t, constant(360.0)))
+    }
+}
@@ -0,0 +1,206 @@
+//! Piecewise cubic interpolation over tables of knots.
+//!
+//! Each interpolator in this family differs only in how it chooses the slope at each
+//! knot. Between knots, the curve is the cubic Hermite segment which matches the
+//! values and slopes at both ends.
+
+use crate::float::constant;
+use crate::slice::check_lengths;
+use crate::{hermite, hermite_derivative, LengthMismatch};
+use num_traits::Float;
+use std::error::Error;
+use std::fmt;
+
+/// The error returned when a table of knots cannot be interpolated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SplineError {
+    /// There are not the same number of `x` and `y` values.
+    LengthMismatch(LengthMismatch),
+    /// There are too few knots: curves need at least two, and linear interpolation one.
+    TooFewPoints,
+    /// The `x` value at this index is not greater than the one before it, or is not finite.
+    NotIncreasing {
+        /// The index of the offending `x` value.
+        index: usize,
+    },
+}
+
+impl fmt::Display for SplineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SplineError::LengthMismatch(mismatch) => mismatch.fmt(f),
+            SplineError::TooFewPoints => write!(f, "too few knots to interpolate"),
+            SplineError::NotIncreasing { index } => {
+                write!(f, "knot {} is not strictly increasing", index)
+            }
+        }
+    }
+}
+
+impl Error for SplineError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SplineError::LengthMismatch(mismatch) => Some(mismatch),
+            _ => None,
+        }
+    }
+}
+
+impl From<LengthMismatch> for SplineError {
+    fn from(mismatch: LengthMismatch) -> SplineError {
+        SplineError::LengthMismatch(mismatch)
+    }
+}
+
+/// Ensure that `xs` and `ys` describe a table which can be interpolated.
+pub(crate) fn check_knots<F: Float>(xs: &[F], ys: &[F]) -> Result<(), SplineError> {
+    check_lengths(xs.len(), &[ys.len()])?;
+    if xs.len() < 2 {
+        return Err(SplineError::TooFewPoints);
+    }
+    if !xs[0].is_finite() {
+        return Err(SplineError::NotIncreasing { index: 0 });
+    }
+    match xs
+        .windows(2)
+        .position(|pair| pair[1] <= pair[0] || !pair[1].is_finite())
+    {
+        Some(index) => Err(SplineError::NotIncreasing { index: index + 1 }),
+        None => Ok(()),
+    }
+}
+
+/// The slope of each interval between consecutive knots.
+pub(crate) fn secants<F: Float>(xs: &[F], ys: &[F]) -> Vec<F> {
+    xs.windows(2)
+        .zip(ys.windows(2))
+        .map(|(x, y)| (y[1] - y[0]) / (x[1] - x[0]))
+        .collect()
+}
+
+/// A piecewise cubic Hermite curve through a table of knots.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PiecewiseCubic<F> {
+    xs: Vec<F>,
+    ys: Vec<F>,
+    slopes: Vec<F>,
+}
+
+impl<F: Float> PiecewiseCubic<F> {
+    /// `xs`, `ys`, and `slopes` must have equal lengths of at least two, and `xs` must be
+    /// strictly increasing.
+    pub(crate) fn new(xs: Vec<F>, ys: Vec<F>, slopes: Vec<F>) -> PiecewiseCubic<F> {
+        PiecewiseCubic { xs, ys, slopes }
+    }
+
+    pub(crate) fn xs(&self) -> &[F] {
+        &self.xs
+    }
+
+    pub(crate) fn ys(&self) -> &[F] {
+        &self.ys
+    }
+
+    pub(crate) fn slopes(&self) -> &[F] {
+        &self.slopes
+    }
+
+    /// The index of the interval containing `x`, extending the first and last intervals
+    /// to cover everything outside the knots.
+    fn interval(&self, x: F) -> usize {
+        let after = self.xs.partition_point(|&knot| knot <= x);
+        after.saturating_sub(1).min(self.xs.len() - 2)
+    }
+
+    /// The Hermite segment containing `x`: its values and tangents, the parameter `t`
+    /// of `x` along it, and its width.
+    fn segment(&self, x: F) -> (F, F, F, F, F, F) {
+        let i = self.interval(x);
+        let h = self.xs[i + 1] - self.xs[i];
+        let t = (x - self.xs[i]) / h;
+        (
+            self.ys[i],
+            self.slopes[i] * h,
+            self.ys[i + 1],
+            self.slopes[i + 1] * h,
+            t,
+            h,
+        )
+    }
+
+    pub(crate) fn eval(&self, x: F) -> F {
+        let (p0, m0, p1, m1, t, _) = self.segment(x);
+        hermite(p0, m0, p1, m1, t)
+    }
+
+    pub(crate) fn eval_bounded(&self, x: F) -> F {
+        let first = self.xs[0];
+        let last = self.xs[self.xs.len() - 1];
+        self.eval(x.max(first).min(last))
+    }
+
+    pub(crate) fn derivative(&self, x: F) -> F {
+        let (p0, m0, p1, m1, t, h) = self.segment(x);
+        hermite_derivative(p0, m0, p1, m1, t) / h
+    }
+
+    pub(crate) fn second_derivative(&self, x: F) -> F {
+        let (p0, m0, p1, m1, t, h) = self.segment(x);
+        let six_t = constant::<F>(6.0) * t;
+        let h00 = six_t + six_t - constant(6.0);
+        let h10 = six_t - constant(4.0);
+        let h11 = six_t - constant(2.0);
+        (p0 * h00 + m0 * h10 - p1 * h00 + m1 * h11) / (h * h)
+    }
+}
+
+/// Forward the evaluation methods of a type wrapping a [`PiecewiseCubic`] in its field `curve`.
+macro_rules! piecewise_cubic_methods {
+    () => {
+        /// The `x` value of each knot.
+        pub fn xs(&self) -> &[F] {
+            self.curve.xs()
+        }
+
+        /// The `y` value of each knot.
+        pub fn ys(&self) -> &[F] {
+            self.curve.ys()
+        }
+
+        /// The slope `dy/dx` of the curve at each knot.
+        pub fn slopes(&self) -> &[F] {
+            self.curve.slopes()
+        }
+
+        /// Evaluate the curve at `x`.
+        ///
+        /// Outside the knots, the curve is extrapolated by extending the first or last
+        /// cubic segment, as [`Lerp::lerp`](crate::Lerp::lerp) extrapolates outside [0..1].
+        pub fn eval(&self, x: F) -> F {
+            self.curve.eval(x)
+        }
+
+        /// Evaluate the curve at `x`, bounding `x` to the range of the knots, as
+        /// [`Lerp::lerp_bounded`](crate::Lerp::lerp_bounded) bounds `t` to [0..1].
+        pub fn eval_bounded(&self, x: F) -> F {
+            self.curve.eval_bounded(x)
+        }
+
+        /// The first derivative `dy/dx` of the curve at `x`.
+        ///
+        /// Outside the knots, this is the derivative of the extrapolated curve.
+        pub fn derivative(&self, x: F) -> F {
+            self.curve.derivative(x)
+        }
+
+        /// The second derivative of the curve at `x`.
+        ///
+        /// At a knot, this is the second derivative of the segment which starts there.
+        /// Outside the knots, this is the second derivative of the extrapolated curve.
+        pub fn second_derivative(&self, x: F) -> F {
+            self.curve.second_derivative(x)
+        }
+    };
+}
+
+pub(crate) use piecewise_cubic_methods;
@@ -0,0 +1,127 @@
+//! Mapping values from one range onto another.
+
+use crate::{InverseLerp, Lerp};
+use num_traits::{Float, One, Zero};
+
+/// Map `value` from the range `from` onto the range `to`.
+///
+/// The position of `value` within `from` is recovered with [`InverseLerp::inverse_lerp`],
+/// and then applied to `to` with [`Lerp::lerp`]. Neither step is bounded, so values outside
+/// of `from` produce values outside of `to`.
+///
+/// Returns `None` when the `from` range is empty.
+///
+/// # Examples
+///
+/// ```
+/// use lerp::remap;
+///
+/// assert_eq!(remap(0.5, (0.0, 1.0), (0.0, 100.0)), Some(50.0));
+/// assert_eq!(remap(15.0, (10.0, 20.0), (100.0, 0.0)), Some(50.0));
+/// assert_eq!(remap(2.0, (0.0, 1.0), (0.0, 100.0)), Some(200.0));
+/// assert_eq!(remap(2.0, (1.0, 1.0), (0.0, 100.0)), None);
+/// ```
+pub fn remap<T, U, F>(value: T, from: (T, T), to: (U, U)) -> Option<U>
+where
+    T: InverseLerp<F>,
+    U: Lerp<F>,
+{
+    let t = from.0.inverse_lerp(from.1, value)?;
+    Some(to.0.lerp(to.1, t))
+}
+
+/// Map `value` from the range `from` onto the range `to` per the [`remap`] function,
+/// bounding the result within `to`.
+///
+/// Returns `None` when the `from` range is empty.
+///
+/// # Examples
+///
+/// ```
+/// use lerp::remap_bounded;
+///
+/// assert_eq!(remap_bounded(0.5, (0.0, 1.0), (0.0, 100.0)), Some(50.0));
+/// assert_eq!(remap_bounded(2.0, (0.0, 1.0), (0.0, 100.0)), Some(100.0));
+/// assert_eq!(remap_bounded(-2.0, (0.0, 1.0), (0.0, 100.0)), Some(0.0));
+/// ```
+pub fn remap_bounded<T, U, F>(value: T, from: (T, T), to: (U, U)) -> Option<U>
+where
+    T: InverseLerp<F>,
+    U: Lerp<F>,
+    F: PartialOrd + Zero + One,
+{
+    let t = from.0.inverse_lerp_bounded(from.1, value)?;
+    Some(to.0.lerp(to.1, t))
+}
+
+/// A reusable mapping from a range of `F` onto a range of `T`.
+///
+/// This is equivalent to repeatedly calling [`remap`], but the scale and offset
+/// which recover `t` from the input value are computed once up front, so remapping
+/// costs a multiply and an add instead of a division.
+///
+/// Because the division is precomputed, results may differ from [`remap`] in the
+/// last bit.
+///
+/// # Example
+///
+/// ```
+/// use lerp::Remap;
+///
+/// let slider = Remap::new((0.0, 1.0), (-50.0, 50.0)).unwrap();
+/// assert_eq!(slider.remap(0.0), -50.0);
+/// assert_eq!(slider.remap(0.25), -25.0);
+/// assert_eq!(slider.remap_bounded(1.5), 50.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Remap<T, F> {
+    start: T,
+    end: T,
+    scale: F,
+    offset: F,
+}
+
+impl<T, F> Remap<T, F>
+where
+    F: Float,
+{
+    /// Create a mapping from the range `from` onto the range `to`.
+    ///
+    /// Returns `None` when the `from` range is empty.
+    pub fn new(from: (F, F), to: (T, T)) -> Option<Remap<T, F>> {
+        if from.0 == from.1 {
+            return None;
+        }
+        let scale = (from.1 - from.0).recip();
+        Some(Remap {
+            start: to.0,
+            end: to.1,
+            scale,
+            offset: -(from.0 * scale),
+        })
+    }
+
+    /// Compute the `t` at which `value` lies within the input range.
+    pub fn parameter(&self, value: F) -> F {
+        value * self.scale + self.offset
+    }
+
+    /// Map `value` from the input range onto the output range.
+    ///
+    /// This is unbounded, like [`remap`].
+    pub fn remap(&self, value: F) -> T
+    where
+        T: Lerp<F> + Copy,
+    {
+        self.start.lerp(self.end, self.parameter(value))
+    }
+
+    /// Map `value` from the input range onto the output range, bounding the result
+    /// within the output range, like [`remap_bounded`].
+    pub fn remap_bounded(&self, value: F) -> T
+    where
+        T: Lerp<F> + Copy,
+    {
+        self.start.lerp_bounded(self.end, self.parameter(value))
+    }
+}
@@ -0,0 +1,329 @@
+//! Spherical interpolation for directions and rotations.
+
+use crate::float::constant;
+use crate::Lerp;
+use num_traits::Float;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// Beyond this dot product, endpoints are so close that spherical interpolation
+/// loses precision, and normalized lerping is indistinguishable from it.
+fn parallel_threshold<F: Float>() -> F {
+    constant(0.9995)
+}
+
+/// Types which can be interpolated along the surface of a sphere.
+///
+/// Unlike plain [`Lerp::lerp`], which cuts through the sphere, these keep the length of
+/// unit-length endpoints, so that the result is still a valid direction or rotation.
+pub trait Slerp<F> {
+    /// Spherical linear interpolation between `self` and `other`.
+    ///
+    /// The result moves along the great arc between the endpoints with constant
+    /// angular speed. `t` is unbounded, so extrapolation continues around the arc.
+    ///
+    /// Both endpoints are expected to have unit length.
+    fn slerp(self, other: Self, t: F) -> Self;
+
+    /// Normalized linear interpolation between `self` and `other`.
+    ///
+    /// This is cheaper than [`Slerp::slerp`] and follows the same path between the
+    /// endpoints, but angular speed is not constant: it is fastest at the midpoint.
+    ///
+    /// Both endpoints are expected to have unit length.
+    fn nlerp(self, other: Self, t: F) -> Self;
+}
+
+/// A minimal three-dimensional vector.
+///
+/// Because this implements `Add` and `Mul<F>`, it also implements [`Lerp`].
+///
+/// # Example
+///
+/// ```
+/// use lerp::{Slerp, Vector3};
+///
+/// let x = Vector3::new(1.0, 0.0, 0.0);
+/// let y = Vector3::new(0.0, 1.0, 0.0);
+/// let half = std::f64::consts::FRAC_1_SQRT_2;
+///
+/// let mid = x.slerp(y, 0.5);
+/// assert!((mid - Vector3::new(half, half, 0.0)).length() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vector3<F> {
+    /// The x component.
+    pub x: F,
+    /// The y component.
+    pub y: F,
+    /// The z component.
+    pub z: F,
+}
+
+impl<F: Float> Vector3<F> {
+    /// Create a vector from its components.
+    pub fn new(x: F, y: F, z: F) -> Vector3<F> {
+        Vector3 { x, y, z }
+    }
+
+    /// The dot product of `self` and `other`.
+    pub fn dot(self, other: Self) -> F {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// The cross product of `self` and `other`.
+    pub fn cross(self, other: Self) -> Self {
+        Vector3 {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+
+    /// The length of this vector.
+    pub fn length(self) -> F {
+        self.dot(self).sqrt()
+    }
+
+    /// This vector, scaled to unit length.
+    ///
+    /// The zero vector has no direction, and normalizes to NaN components.
+    pub fn normalize(self) -> Self {
+        self * self.length().recip()
+    }
+
+    /// Some unit vector perpendicular to this one.
+    fn any_perpendicular(self) -> Self {
+        let (x, y, z) = (self.x.abs(), self.y.abs(), self.z.abs());
+        let axis = if x <= y && x <= z {
+            Vector3::new(F::one(), F::zero(), F::zero())
+        } else if y <= z {
+            Vector3::new(F::zero(), F::one(), F::zero())
+        } else {
+            Vector3::new(F::zero(), F::zero(), F::one())
+        };
+        self.cross(axis).normalize()
+    }
+
+    /// The unit vector perpendicular to this unit vector, in the direction of `other`,
+    /// along with the sine of the angle between them.
+    ///
+    /// When `other` is numerically parallel or opposite to `self`, the direction is
+    /// meaningless, and some perpendicular is chosen instead.
+    fn perpendicular_towards(self, other: Self) -> (Self, F) {
+        let perpendicular = other - self * self.dot(other);
+        let sin_theta = perpendicular.length();
+        if sin_theta < F::epsilon().sqrt() {
+            (self.any_perpendicular(), sin_theta)
+        } else {
+            (perpendicular * sin_theta.recip(), sin_theta)
+        }
+    }
+}
+
+impl<F: Float> Add for Vector3<F> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Vector3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<F: Float> Sub for Vector3<F> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Vector3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<F: Float> Mul<F> for Vector3<F> {
+    type Output = Self;
+
+    fn mul(self, scalar: F) -> Self {
+        Vector3::new(self.x * scalar, self.y * scalar, self.z * scalar)
+    }
+}
+
+impl<F: Float> Neg for Vector3<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Vector3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+/// Unit vectors interpolate along the great circle through both endpoints.
+///
+/// When the endpoints point in numerically opposite directions, there is no unique great
+/// circle between them; the path then passes through an arbitrary perpendicular direction.
+impl<F: Float> Slerp<F> for Vector3<F> {
+    fn slerp(self, other: Self, t: F) -> Self {
+        let dot = self.dot(other);
+        if dot > parallel_threshold() {
+            return self.lerp(other, t).normalize();
+        }
+        // rotate `self` towards `other` within their plane; unlike dividing by the sine
+        // of the angle, this stays precise as the endpoints approach opposite directions
+        let (perpendicular, sin_theta) = self.perpendicular_towards(other);
+        let angle = sin_theta.atan2(dot) * t;
+        self * angle.cos() + perpendicular * angle.sin()
+    }
+
+    fn nlerp(self, other: Self, t: F) -> Self {
+        let (perpendicular, sin_theta) = self.perpendicular_towards(other);
+        if self.dot(other) < F::zero() && sin_theta < F::epsilon().sqrt() {
+            // the straight line between opposite directions passes through the origin,
+            // so detour via a perpendicular direction instead.
+            let two = constant::<F>(2.0);
+            if t < two.recip() {
+                self.lerp(perpendicular, t * two).normalize()
+            } else {
+                perpendicular.lerp(other, t * two - F::one()).normalize()
+            }
+        } else {
+            self.lerp(other, t).normalize()
+        }
+    }
+}
+
+/// A minimal quaternion, for representing rotations.
+///
+/// Because this implements `Add` and `Mul<F>`, it also implements [`Lerp`].
+///
+/// # Example
+///
+/// ```
+/// use lerp::{Quaternion, Slerp, Vector3};
+/// use std::f64::consts::PI;
+///
+/// let z = Vector3::new(0.0, 0.0, 1.0);
+/// let start = Quaternion::identity();
+/// let end = Quaternion::from_axis_angle(z, PI / 2.0);
+///
+/// let quarter = start.slerp(end, 0.5);
+/// let expect = Quaternion::from_axis_angle(z, PI / 4.0);
+/// assert!((quarter.dot(expect) - 1.0).abs() < 1e-12);
+///
+/// let rotated = quarter.rotate(Vector3::new(1.0, 0.0, 0.0));
+/// assert!((rotated.x - rotated.y).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Quaternion<F> {
+    /// The scalar component.
+    pub w: F,
+    /// The i component.
+    pub x: F,
+    /// The j component.
+    pub y: F,
+    /// The k component.
+    pub z: F,
+}
+
+impl<F: Float> Quaternion<F> {
+    /// Create a quaternion from its components.
+    pub fn new(w: F, x: F, y: F, z: F) -> Quaternion<F> {
+        Quaternion { w, x, y, z }
+    }
+
+    /// The quaternion representing no rotation.
+    pub fn identity() -> Quaternion<F> {
+        Quaternion::new(F::one(), F::zero(), F::zero(), F::zero())
+    }
+
+    /// The quaternion representing a rotation by `angle` radians around `axis`.
+    ///
+    /// `axis` is expected to have unit length.
+    pub fn from_axis_angle(axis: Vector3<F>, angle: F) -> Quaternion<F> {
+        let half = angle / constant(2.0);
+        let (sin, cos) = half.sin_cos();
+        Quaternion::new(cos, axis.x * sin, axis.y * sin, axis.z * sin)
+    }
+
+    /// The dot product of `self` and `other`.
+    pub fn dot(self, other: Self) -> F {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// The length of this quaternion.
+    pub fn length(self) -> F {
+        self.dot(self).sqrt()
+    }
+
+    /// This quaternion, scaled to unit length.
+    pub fn normalize(self) -> Self {
+        self * self.length().recip()
+    }
+
+    /// Rotate `vector` by the rotation this unit quaternion represents.
+    pub fn rotate(self, vector: Vector3<F>) -> Vector3<F> {
+        let axis = Vector3::new(self.x, self.y, self.z);
+        let two = constant::<F>(2.0);
+        let t = axis.cross(vector) * two;
+        vector + t * self.w + axis.cross(t)
+    }
+}
+
+impl<F: Float> Add for Quaternion<F> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Quaternion::new(
+            self.w + other.w,
+            self.x + other.x,
+            self.y + other.y,
+            self.z + other.z,
+        )
+    }
+}
+
+impl<F: Float> Mul<F> for Quaternion<F> {
+    type Output = Self;
+
+    fn mul(self, scalar: F) -> Self {
+        Quaternion::new(
+            self.w * scalar,
+            self.x * scalar,
+            self.y * scalar,
+            self.z * scalar,
+        )
+    }
+}
+
+impl<F: Float> Neg for Quaternion<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Quaternion::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
+
+/// Unit quaternions interpolate along the shortest rotation between the endpoints.
+///
+/// `q` and `-q` represent the same rotation, so when the endpoints are more than a
+/// half turn apart, `other` is negated to take the shorter path. Either way, the
+/// result at `t == 1` represents the same rotation as `other`.
+impl<F: Float> Slerp<F> for Quaternion<F> {
+    fn slerp(self, other: Self, t: F) -> Self {
+        let (other, dot) = match self.dot(other) {
+            dot if dot < F::zero() => (-other, -dot),
+            dot => (other, dot),
+        };
+        if dot > parallel_threshold() {
+            self.lerp(other, t).normalize()
+        } else {
+            let theta = dot.min(F::one()).acos();
+            let sin_theta = theta.sin();
+            self * (((F::one() - t) * theta).sin() / sin_theta)
+                + other * ((t * theta).sin() / sin_theta)
+        }
+    }
+
+    fn nlerp(self, other: Self, t: F) -> Self {
+        let other = if self.dot(other) < F::zero() {
+            -other
+        } else {
+            other
+        };
+        self.lerp(other, t).normalize()
+    }
+}
@@ -0,0 +1,119 @@
+//! Element-wise lerping over slices.
+
+use crate::Lerp;
+use std::error::Error;
+use std::fmt;
+
+/// The error returned when lerping between slices of differing lengths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LengthMismatch {
+    /// The length of the first slice, which all others must match.
+    pub expected: usize,
+    /// The length of the first slice which did not match.
+    pub actual: usize,
+}
+
+impl fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "slice length mismatch: expected {}, found {}",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl Error for LengthMismatch {}
+
+pub(crate) fn check_lengths(expected: usize, others: &[usize]) -> Result<(), LengthMismatch> {
+    match others.iter().find(|&&actual| actual != expected) {
+        Some(&actual) => Err(LengthMismatch { expected, actual }),
+        None => Ok(()),
+    }
+}
+
+/// Lerp each element of `a` towards the corresponding element of `b`, writing the results to `out`.
+///
+/// All three slices must have the same length; otherwise, `out` is left untouched.
+///
+/// # Examples
+///
+/// ```
+/// use lerp::lerp_slice;
+///
+/// let mut out = [0.0; 3];
+/// lerp_slice(&[0.0, 1.0, 2.0], &[2.0, 3.0, 4.0], 0.5, &mut out).unwrap();
+/// assert_eq!(out, [1.0, 2.0, 3.0]);
+/// ```
+///
+/// Mismatched lengths:
+///
+/// ```
+/// # use lerp::{lerp_slice, LengthMismatch};
+/// let mut out = [0.0; 3];
+/// assert_eq!(
+///     lerp_slice(&[0.0, 1.0, 2.0], &[2.0, 3.0], 0.5, &mut out),
+///     Err(LengthMismatch { expected: 3, actual: 2 }),
+/// );
+/// ```
+pub fn lerp_slice<T, F>(a: &[T], b: &[T], t: F, out: &mut [T]) -> Result<(), LengthMismatch>
+where
+    T: Lerp<F> + Copy,
+    F: Copy,
+{
+    check_lengths(a.len(), &[b.len(), out.len()])?;
+    for ((out, &a), &b) in out.iter_mut().zip(a).zip(b) {
+        *out = a.lerp(b, t);
+    }
+    Ok(())
+}
+
+/// Lerp each element of `a` towards the corresponding element of `b`, in place.
+///
+/// Both slices must have the same length; otherwise, `a` is left untouched.
+///
+/// # Example
+///
+/// ```
+/// use lerp::lerp_slice_in_place;
+///
+/// let mut a = [0.0, 1.0, 2.0];
+/// lerp_slice_in_place(&mut a, &[2.0, 3.0, 4.0], 0.5).unwrap();
+/// assert_eq!(a, [1.0, 2.0, 3.0]);
+/// ```
+pub fn lerp_slice_in_place<T, F>(a: &mut [T], b: &[T], t: F) -> Result<(), LengthMismatch>
+where
+    T: Lerp<F> + Copy,
+    F: Copy,
+{
+    check_lengths(a.len(), &[b.len()])?;
+    for (a, &b) in a.iter_mut().zip(b) {
+        a.lerp_to(b, t);
+    }
+    Ok(())
+}
+
+/// Lerp each element of `a` towards the corresponding element of `b`, collecting the results.
+///
+/// Both slices must have the same length.
+///
+/// `Vec<T>` can't implement [`Lerp`] itself, as that would conflict with the blanket
+/// implementation, should the standard library ever implement `Add` for it.
+///
+/// # Example
+///
+/// ```
+/// use lerp::lerp_vec;
+///
+/// let a = vec![0.0, 1.0, 2.0];
+/// let b = vec![2.0, 3.0, 4.0];
+/// assert_eq!(lerp_vec(&a, &b, 0.5), Ok(vec![1.0, 2.0, 3.0]));
+/// ```
+pub fn lerp_vec<T, F>(a: &[T], b: &[T], t: F) -> Result<Vec<T>, LengthMismatch>
+where
+    T: Lerp<F> + Copy,
+    F: Copy,
+{
+    check_lengths(a.len(), &[b.len()])?;
+    Ok(a.iter().zip(b).map(|(&a, &b)| a.lerp(b, t)).collect())
+}
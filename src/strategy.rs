@@ -0,0 +1,135 @@
+//! Alternative formulas for lerping floating point values.
+
+use crate::Lerp;
+use num_traits::Float;
+
+/// The formula used to lerp between two floating point values.
+///
+/// In exact arithmetic, these all compute the same result. In floating point, they
+/// trade speed against the guarantees they make:
+///
+/// | Strategy                                         | Exact at `t == 0` | Exact at `t == 1` | Monotonic |
+/// |--------------------------------------------------|-------------------|-------------------|-----------|
+/// | [`TwoMultiply`](LerpStrategy::TwoMultiply)       | yes               | yes               | no        |
+/// | [`SingleMultiply`](LerpStrategy::SingleMultiply) | yes               | no                | yes       |
+/// | [`Fma`](LerpStrategy::Fma)                       | yes               | no                | yes       |
+/// | [`Precise`](LerpStrategy::Precise)               | yes               | yes               | yes       |
+///
+/// Monotonic means that as `t` increases, the result never moves back towards `a`: it is
+/// non-decreasing in `t` when `b > a`, and non-increasing when `b < a`.
+///
+/// # Example
+///
+/// ```
+/// use lerp::LerpStrategy;
+///
+/// assert_eq!(LerpStrategy::Precise.lerp(3.0, 5.0, 0.5), 4.0);
+/// assert_eq!(LerpStrategy::Precise.lerp(1e16, 1.0, 1.0), 1.0);
+/// assert_ne!(LerpStrategy::SingleMultiply.lerp(1e16, 1.0, 1.0), 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LerpStrategy {
+    /// `a * (1 - t) + b * t`
+    ///
+    /// This is the formula used by the default implementation of [`Lerp`].
+    #[default]
+    TwoMultiply,
+    /// `a + (b - a) * t`
+    SingleMultiply,
+    /// `a + (b - a) * t`, computed with a fused multiply-add.
+    ///
+    /// This rounds only once, but is slow on targets without hardware FMA support.
+    Fma,
+    /// A branching formula which is exact at both endpoints, monotonic, and bounded
+    /// by the endpoints for `t` in [0..1].
+    ///
+    /// Also, lerping between equal endpoints always returns that endpoint.
+    Precise,
+}
+
+impl LerpStrategy {
+    /// Interpolate and extrapolate between `a` and `b` using this strategy.
+    ///
+    /// `t` is unbounded, per [`Lerp::lerp`].
+    pub fn lerp<F: Float>(self, a: F, b: F, t: F) -> F {
+        match self {
+            LerpStrategy::TwoMultiply => a * (F::one() - t) + b * t,
+            LerpStrategy::SingleMultiply => a + (b - a) * t,
+            LerpStrategy::Fma => t.mul_add(b - a, a),
+            LerpStrategy::Precise => {
+                let zero = F::zero();
+                if (a <= zero && b >= zero) || (a >= zero && b <= zero) {
+                    // with endpoints of differing sign, the two-multiply formula
+                    // is already both exact and monotonic.
+                    t * b + (F::one() - t) * a
+                } else if t == F::one() {
+                    b
+                } else {
+                    // when extrapolating beyond `b`, or interpolating up to it, the
+                    // single-multiply result may round past `b`; pin it there.
+                    let x = a + t * (b - a);
+                    if (t > F::one()) == (b > a) {
+                        b.max(x)
+                    } else {
+                        b.min(x)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Interpolate between `a` and `b` using this strategy, bounding `t`
+    /// in the inclusive range [0..1].
+    pub fn lerp_bounded<F: Float>(self, a: F, b: F, t: F) -> F {
+        let t = match t {
+            t if t < F::zero() => F::zero(),
+            t if t > F::one() => F::one(),
+            t => t,
+        };
+        self.lerp(a, b, t)
+    }
+}
+
+/// A floating point value which lerps using a particular [`LerpStrategy`].
+///
+/// When lerping two `WithStrategy` values, the strategy of `self` is used and
+/// preserved in the result; the strategy of `other` is ignored.
+///
+/// Since this implements `Lerp`, it also works with [`LerpIter`](crate::LerpIter)
+/// and within derived implementations.
+///
+/// # Example
+///
+/// ```
+/// use lerp::{Lerp, LerpStrategy, WithStrategy};
+///
+/// let a = WithStrategy::new(1e16, LerpStrategy::Precise);
+/// let b = WithStrategy::new(1.0, LerpStrategy::Precise);
+/// assert_eq!(a.lerp(b, 1.0).value, 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct WithStrategy<F> {
+    /// The wrapped value.
+    pub value: F,
+    /// The strategy used when lerping from this value.
+    pub strategy: LerpStrategy,
+}
+
+impl<F> WithStrategy<F> {
+    /// Wrap `value` so that it lerps using `strategy`.
+    pub fn new(value: F, strategy: LerpStrategy) -> WithStrategy<F> {
+        WithStrategy { value, strategy }
+    }
+}
+
+impl<F> Lerp<F> for WithStrategy<F>
+where
+    F: Float,
+{
+    fn lerp(self, other: Self, t: F) -> Self {
+        WithStrategy {
+            value: self.strategy.lerp(self.value, other.value, t),
+            strategy: self.strategy,
+        }
+    }
+}
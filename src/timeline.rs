@@ -0,0 +1,262 @@
+//! Timelines, which choreograph several animations over time.
+
+use crate::easing::Easing;
+use crate::{Lerp, Tween};
+use num_traits::Float;
+
+/// Values which change over time, starting at time zero.
+pub trait Animation<F> {
+    /// The type of the animated value.
+    type Value;
+
+    /// The time at which the animation completes, or `None` if it never does.
+    fn duration(&self) -> Option<F>;
+
+    /// The value of the animation at `time`.
+    fn value_at(&self, time: F) -> Self::Value;
+}
+
+impl<T, F, E> Animation<F> for Tween<T, F, E>
+where
+    T: Lerp<F> + Copy,
+    F: Float,
+    E: Easing<F>,
+{
+    type Value = T;
+
+    fn duration(&self) -> Option<F> {
+        self.total_duration()
+    }
+
+    fn value_at(&self, time: F) -> T {
+        Tween::value_at(self, time)
+    }
+}
+
+/// Where to place an animation on a [`Timeline`], before applying an offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Position<'a, F> {
+    /// At the start of the timeline: time zero.
+    Start,
+    /// At the end of the timeline, per [`Timeline::end`].
+    End,
+    /// At the same time as the most recently added animation, or time zero if there is none.
+    WithPrevious,
+    /// At this time.
+    Time(F),
+    /// At the time of this label.
+    Label(&'a str),
+}
+
+struct Entry<T, F> {
+    start: F,
+    animation: Box<dyn Animation<F, Value = T>>,
+}
+
+/// A collection of animations, each starting at its own time, which can be played or
+/// scrubbed together.
+///
+/// Animations are identified by their index, in the order they were added.
+///
+/// # Example
+///
+/// ```
+/// use lerp::{Position, Timeline, Tween};
+///
+/// let mut timeline = Timeline::new();
+/// let a = timeline.then(Tween::new(0.0, 1.0, 1.0));
+/// let b = timeline.then(Tween::new(1.0, 3.0, 2.0));
+/// let c = timeline.with(Tween::new(10.0, 20.0, 1.0));
+/// timeline.add_label("finale", timeline.end());
+/// let d = timeline
+///     .insert(Position::Label("finale"), 0.2, Tween::new(0.0, 5.0, 1.0))
+///     .unwrap();
+///
+/// assert_eq!(timeline.start_of(c), Some(1.0));
+/// assert_eq!(timeline.start_of(d), Some(3.2));
+/// assert_eq!(timeline.duration(), Some(4.2));
+///
+/// // a then b animate the same value in sequence
+/// assert_eq!(timeline.value_at(0.5), Some(0.5));
+/// assert_eq!(timeline.value_of(b, 2.0), Some(2.0));
+/// assert_eq!(timeline.value_of(c, 2.0), Some(20.0));
+/// assert_eq!(timeline.value_of(a, 2.0), Some(1.0));
+/// ```
+pub struct Timeline<T, F> {
+    entries: Vec<Entry<T, F>>,
+    labels: Vec<(String, F)>,
+    time: F,
+}
+
+impl<T, F: Float> Default for Timeline<T, F> {
+    fn default() -> Timeline<T, F> {
+        Timeline {
+            entries: Vec::new(),
+            labels: Vec::new(),
+            time: F::zero(),
+        }
+    }
+}
+
+impl<T, F: Float> Timeline<T, F> {
+    /// Create an empty timeline.
+    pub fn new() -> Timeline<T, F> {
+        Timeline::default()
+    }
+
+    /// The number of animations on this timeline.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether this timeline has no animations.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The latest time at which an animation completes, ignoring animations which never
+    /// complete. This is zero for an empty timeline.
+    pub fn end(&self) -> F {
+        self.entries
+            .iter()
+            .filter_map(|entry| {
+                entry
+                    .animation
+                    .duration()
+                    .map(|duration| entry.start + duration)
+            })
+            .fold(F::zero(), F::max)
+    }
+
+    /// The time at which every animation has completed, or `None` if some animation never
+    /// completes.
+    pub fn duration(&self) -> Option<F> {
+        self.entries.iter().try_fold(F::zero(), |end, entry| {
+            Some(end.max(entry.start + entry.animation.duration()?))
+        })
+    }
+
+    /// Add `animation` at `position`, adjusted by `offset`, returning its index.
+    ///
+    /// Returns `None`, without adding the animation, if `position` names an unknown label.
+    pub fn insert<A>(&mut self, position: Position<'_, F>, offset: F, animation: A) -> Option<usize>
+    where
+        A: 'static + Animation<F, Value = T>,
+    {
+        let base = match position {
+            Position::Start => F::zero(),
+            Position::End => self.end(),
+            Position::WithPrevious => self.entries.last().map_or(F::zero(), |entry| entry.start),
+            Position::Time(time) => time,
+            Position::Label(label) => self.label(label)?,
+        };
+        self.entries.push(Entry {
+            start: base + offset,
+            animation: Box::new(animation),
+        });
+        Some(self.entries.len() - 1)
+    }
+
+    /// Add `animation` to start at the end of the timeline, returning its index.
+    pub fn then<A>(&mut self, animation: A) -> usize
+    where
+        A: 'static + Animation<F, Value = T>,
+    {
+        self.insert(Position::End, F::zero(), animation)
+            .expect("only labels can be missing")
+    }
+
+    /// Add `animation` to start alongside the most recently added animation, returning its index.
+    pub fn with<A>(&mut self, animation: A) -> usize
+    where
+        A: 'static + Animation<F, Value = T>,
+    {
+        self.insert(Position::WithPrevious, F::zero(), animation)
+            .expect("only labels can be missing")
+    }
+
+    /// Name a time on this timeline, so that animations can be placed relative to it.
+    ///
+    /// Replaces any existing label with the same name.
+    pub fn add_label(&mut self, label: impl Into<String>, time: F) {
+        let label = label.into();
+        match self.labels.iter_mut().find(|(name, _)| *name == label) {
+            Some((_, existing)) => *existing = time,
+            None => self.labels.push((label, time)),
+        }
+    }
+
+    /// The time of `label`, if it exists.
+    pub fn label(&self, label: &str) -> Option<F> {
+        self.labels
+            .iter()
+            .find(|(name, _)| name == label)
+            .map(|&(_, time)| time)
+    }
+
+    /// The time at which the animation at `index` starts, if it exists.
+    pub fn start_of(&self, index: usize) -> Option<F> {
+        self.entries.get(index).map(|entry| entry.start)
+    }
+
+    /// The value at `time` of the animation at `index`, if it exists.
+    ///
+    /// Before the animation starts, this is its value at its own time zero.
+    pub fn value_of(&self, index: usize, time: F) -> Option<T> {
+        self.entries.get(index).map(|entry| {
+            entry
+                .animation
+                .value_at((time - entry.start).max(F::zero()))
+        })
+    }
+
+    /// The value at `time` of every animation, in the order they were added.
+    pub fn values_at(&self, time: F) -> impl '_ + Iterator<Item = T> {
+        (0..self.entries.len()).filter_map(move |index| self.value_of(index, time))
+    }
+
+    /// The value at `time` of the animation which started most recently, preferring the one
+    /// added last among those starting together.
+    ///
+    /// This suits timelines which animate a single value, handing it from one animation
+    /// to the next. Before any animation starts, it is the value of the earliest one.
+    ///
+    /// Returns `None` if the timeline is empty.
+    pub fn value_at(&self, time: F) -> Option<T> {
+        let mut latest: Option<(usize, F)> = None;
+        let mut earliest: Option<(usize, F)> = None;
+        for (index, entry) in self.entries.iter().enumerate() {
+            if entry.start <= time && !matches!(latest, Some((_, start)) if entry.start < start) {
+                latest = Some((index, entry.start));
+            }
+            if !matches!(earliest, Some((_, start)) if entry.start >= start) {
+                earliest = Some((index, entry.start));
+            }
+        }
+        let (index, _) = latest.or(earliest)?;
+        self.value_of(index, time)
+    }
+
+    /// The position of the playhead.
+    pub fn time(&self) -> F {
+        self.time
+    }
+
+    /// Move the playhead to `time`, returning the value there per [`Timeline::value_at`].
+    pub fn seek(&mut self, time: F) -> Option<T> {
+        self.time = time;
+        self.value_at(time)
+    }
+
+    /// Move the playhead forward by `dt`, returning the value there per [`Timeline::value_at`].
+    pub fn advance(&mut self, dt: F) -> Option<T> {
+        self.seek(self.time + dt)
+    }
+
+    /// Whether the playhead has passed the end of every animation.
+    ///
+    /// This is never true if some animation never completes.
+    pub fn is_finished(&self) -> bool {
+        matches!(self.duration(), Some(duration) if self.time >= duration)
+    }
+}
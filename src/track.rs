@@ -0,0 +1,290 @@
+//! Keyframe animation tracks.
+
+use crate::catmull_rom::barry_goldman;
+use crate::Lerp;
+use num_traits::Float;
+
+/// How a [`Track`] interpolates from a key to the next one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum KeyMode {
+    /// Hold this key's value until the next key.
+    Step,
+    /// Lerp from this key's value to the next key's value.
+    #[default]
+    Linear,
+    /// Follow a smooth curve through this key and the next, shaped by their neighbors.
+    ///
+    /// The curve is a Catmull-Rom spline whose knots are the key times, so its speed
+    /// follows the timing of unevenly spaced keys. It is not shape-preserving: it may
+    /// overshoot the values of neighboring keys, such as between two equal keys at a peak.
+    Cubic,
+}
+
+/// A value at a point in time, and how to interpolate onward from it.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Key<T, F> {
+    /// When this key occurs. This must not be NaN.
+    pub time: F,
+    /// The value at this key.
+    pub value: T,
+    /// How to interpolate from this key to the next one.
+    pub mode: KeyMode,
+}
+
+impl<T, F> Key<T, F> {
+    /// Create a key.
+    pub fn new(time: F, value: T, mode: KeyMode) -> Key<T, F> {
+        Key { time, value, mode }
+    }
+}
+
+/// How a [`Track`] maps times outside its first and last keys back onto them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WrapMode {
+    /// Hold the first key's value before it, and the last key's value after it.
+    #[default]
+    Clamp,
+    /// Repeat the track from the first key after reaching the last.
+    Loop,
+    /// Play the track backwards after reaching the last key, then forwards again.
+    PingPong,
+}
+
+/// A sequence of keys, sorted by time, which can be sampled at any time.
+///
+/// Each key's [`KeyMode`] determines how the track interpolates from it to the next key.
+/// The mode of the last key is unused.
+///
+/// # Example
+///
+/// ```
+/// use lerp::{Key, KeyMode, Track, WrapMode};
+///
+/// let mut track = Track::new();
+/// track.insert(Key::new(0.0, 0.0, KeyMode::Linear));
+/// track.insert(Key::new(2.0, 10.0, KeyMode::Step));
+/// track.insert(Key::new(3.0, 0.0, KeyMode::Linear));
+///
+/// assert_eq!(track.sample(1.0), Some(5.0));
+/// assert_eq!(track.sample(2.5), Some(10.0));
+/// assert_eq!(track.sample(4.0), Some(0.0));
+///
+/// track.wrap = WrapMode::Loop;
+/// assert_eq!(track.sample(4.0), Some(5.0));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Track<T, F> {
+    keys: Vec<Key<T, F>>,
+    /// How times outside the first and last keys are sampled.
+    pub wrap: WrapMode,
+}
+
+impl<T, F> Default for Track<T, F> {
+    fn default() -> Track<T, F> {
+        Track {
+            keys: Vec::new(),
+            wrap: WrapMode::default(),
+        }
+    }
+}
+
+impl<T, F: Float> Track<T, F> {
+    /// Create an empty track which clamps times outside its keys.
+    pub fn new() -> Track<T, F> {
+        Track::default()
+    }
+
+    /// Create a track from a collection of keys in any order.
+    ///
+    /// Where several keys share a time, only the last of them is kept.
+    pub fn from_keys(keys: impl IntoIterator<Item = Key<T, F>>) -> Track<T, F> {
+        let mut track = Track::new();
+        for key in keys {
+            track.insert(key);
+        }
+        track
+    }
+
+    /// The keys of this track, sorted by time.
+    pub fn keys(&self) -> &[Key<T, F>] {
+        &self.keys
+    }
+
+    /// The number of keys in this track.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Whether this track has no keys.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// The time from the first key to the last, or zero if there are no keys.
+    pub fn duration(&self) -> F {
+        match (self.keys.first(), self.keys.last()) {
+            (Some(first), Some(last)) => last.time - first.time,
+            _ => F::zero(),
+        }
+    }
+
+    /// Insert a key, keeping the keys sorted by time.
+    ///
+    /// If a key already exists at the same time, it is replaced and returned.
+    pub fn insert(&mut self, key: Key<T, F>) -> Option<Key<T, F>> {
+        let index = self
+            .keys
+            .partition_point(|existing| existing.time < key.time);
+        match self.keys.get_mut(index) {
+            Some(existing) if existing.time == key.time => Some(std::mem::replace(existing, key)),
+            _ => {
+                self.keys.insert(index, key);
+                None
+            }
+        }
+    }
+
+    /// Remove and return the key at `index`, or `None` if it is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Option<Key<T, F>> {
+        if index < self.keys.len() {
+            Some(self.keys.remove(index))
+        } else {
+            None
+        }
+    }
+
+    /// Map `time` into the range from the first key to the last, per `self.wrap`.
+    ///
+    /// There must be at least two keys, with distinct times.
+    fn wrap_time(&self, time: F) -> F {
+        let first = self.keys[0].time;
+        let duration = self.duration();
+        let wrap = |offset: F, period: F| offset - period * (offset / period).floor();
+        match self.wrap {
+            WrapMode::Clamp => time.max(first).min(first + duration),
+            WrapMode::Loop => first + wrap(time - first, duration),
+            WrapMode::PingPong => {
+                let offset = wrap(time - first, duration + duration);
+                if offset > duration {
+                    first + duration + duration - offset
+                } else {
+                    first + offset
+                }
+            }
+        }
+    }
+
+    /// The index of the last key at or before `time`, which must lie within the keys.
+    ///
+    /// Tries `hint` and the key after it before searching the whole track.
+    fn locate(&self, time: F, hint: usize) -> usize {
+        let contains = |index: usize| match self.keys.get(index..index + 2) {
+            Some([start, end]) => start.time <= time && time < end.time,
+            _ => false,
+        };
+        if contains(hint) {
+            hint
+        } else if contains(hint + 1) {
+            hint + 1
+        } else {
+            self.keys
+                .partition_point(|key| key.time <= time)
+                .saturating_sub(1)
+        }
+    }
+}
+
+impl<T, F> Track<T, F>
+where
+    T: Lerp<F> + Copy,
+    F: Float,
+{
+    /// Sample the track at `time`.
+    ///
+    /// Returns `None` if the track has no keys.
+    pub fn sample(&self, time: F) -> Option<T> {
+        self.sampler().sample(time)
+    }
+
+    /// Create a sampler, which samples this track more efficiently when successive times
+    /// are close together, as during playback.
+    pub fn sampler(&self) -> TrackSampler<'_, T, F> {
+        TrackSampler {
+            track: self,
+            segment: 0,
+        }
+    }
+
+    /// Evaluate the segment starting at key `index` at `time`.
+    fn eval(&self, index: usize, time: F) -> T {
+        let start = &self.keys[index];
+        let end = match self.keys.get(index + 1) {
+            Some(end) => end,
+            None => return start.value,
+        };
+        let t = (time - start.time) / (end.time - start.time);
+        match start.mode {
+            KeyMode::Step => start.value,
+            KeyMode::Linear => start.value.lerp(end.value, t),
+            KeyMode::Cubic => {
+                // reflect the first and last keys to stand in for missing neighbors
+                let span = end.time - start.time;
+                let (before, before_time) = match index.checked_sub(1) {
+                    Some(before) => (self.keys[before].value, self.keys[before].time),
+                    None => (start.value.lerp(end.value, -F::one()), start.time - span),
+                };
+                let (after, after_time) = match self.keys.get(index + 2) {
+                    Some(after) => (after.value, after.time),
+                    None => (end.value.lerp(start.value, -F::one()), end.time + span),
+                };
+                barry_goldman(
+                    &[before, start.value, end.value, after],
+                    &[before_time, start.time, end.time, after_time],
+                    t,
+                )
+            }
+        }
+    }
+}
+
+/// Samples a [`Track`], remembering the segment of the last sample to speed up the next.
+///
+/// # Example
+///
+/// ```
+/// use lerp::{Key, KeyMode, Track};
+///
+/// let track = Track::from_keys(vec![
+///     Key::new(0.0, 0.0, KeyMode::Linear),
+///     Key::new(1.0, 1.0, KeyMode::Linear),
+///     Key::new(2.0, 0.0, KeyMode::Linear),
+/// ]);
+/// let mut sampler = track.sampler();
+/// let frames: Vec<_> = (0..5).map(|frame| sampler.sample(frame as f64 * 0.5)).collect();
+/// assert_eq!(frames, vec![Some(0.0), Some(0.5), Some(1.0), Some(0.5), Some(0.0)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TrackSampler<'a, T, F> {
+    track: &'a Track<T, F>,
+    segment: usize,
+}
+
+impl<'a, T, F> TrackSampler<'a, T, F>
+where
+    T: Lerp<F> + Copy,
+    F: Float,
+{
+    /// Sample the track at `time`.
+    ///
+    /// Returns `None` if the track has no keys.
+    pub fn sample(&mut self, time: F) -> Option<T> {
+        let track = self.track;
+        let first = track.keys.first()?;
+        if track.duration() == F::zero() {
+            return Some(first.value);
+        }
+        let time = track.wrap_time(time);
+        self.segment = track.locate(time, self.segment);
+        Some(track.eval(self.segment, time))
+    }
+}
@@ -0,0 +1,217 @@
+//! Tweens: lerps driven by elapsed time.
+
+use crate::easing::{linear, Easing};
+use crate::float::constant;
+use crate::Lerp;
+use num_traits::Float;
+
+/// How many times a [`Tween`] plays after its first play.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Repeat {
+    /// Play this many more times.
+    Times(u32),
+    /// Play forever.
+    Forever,
+}
+
+impl Default for Repeat {
+    fn default() -> Repeat {
+        Repeat::Times(0)
+    }
+}
+
+/// An animation from `start` to `end` over a fixed duration.
+///
+/// Time is measured in whatever unit `duration` is given in. Before and during play,
+/// the tween's progress is reshaped by its easing `E`, which may overshoot. Any
+/// [`Easing`] can be used, including closures; by default, it's a function pointer.
+/// Once complete, the tween holds its final value, as [`Lerp::lerp_bounded`] does.
+///
+/// # Example
+///
+/// ```
+/// use lerp::easing;
+/// use lerp::{Repeat, Tween};
+///
+/// let mut tween = Tween::new(0.0, 10.0, 2.0)
+///     .with_delay(1.0)
+///     .with_easing(easing::quad_in);
+///
+/// assert_eq!(tween.advance(1.0), 0.0);
+/// assert_eq!(tween.advance(1.0), 2.5);
+/// assert_eq!(tween.advance(1.0), 10.0);
+/// assert!(tween.is_finished());
+/// assert_eq!(tween.advance(100.0), 10.0);
+///
+/// let yoyo = Tween::new(0.0, 10.0, 1.0)
+///     .with_repeat(Repeat::Times(1))
+///     .with_yoyo(true);
+/// assert_eq!(yoyo.value_at(1.25), 7.5);
+/// assert_eq!(yoyo.value_at(5.0), 0.0);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Tween<T, F, E = fn(F) -> F> {
+    start: T,
+    end: T,
+    duration: F,
+    delay: F,
+    easing: E,
+    repeat: Repeat,
+    yoyo: bool,
+    elapsed: F,
+}
+
+impl<T, F> Tween<T, F>
+where
+    T: Lerp<F> + Copy,
+    F: Float,
+{
+    /// Create a tween from `start` to `end` over `duration`, which plays once, linearly,
+    /// without delay.
+    ///
+    /// A zero or negative duration completes instantly.
+    pub fn new(start: T, end: T, duration: F) -> Tween<T, F> {
+        Tween {
+            start,
+            end,
+            duration,
+            delay: F::zero(),
+            easing: linear,
+            repeat: Repeat::default(),
+            yoyo: false,
+            elapsed: F::zero(),
+        }
+    }
+}
+
+impl<T, F, E> Tween<T, F, E>
+where
+    T: Lerp<F> + Copy,
+    F: Float,
+    E: Easing<F>,
+{
+    /// Wait for `delay` before starting to play, holding the start value.
+    pub fn with_delay(self, delay: F) -> Tween<T, F, E> {
+        Tween { delay, ..self }
+    }
+
+    /// Reshape the progress of each play with `easing`, such as a function from the
+    /// [`easing`](crate::easing) module.
+    pub fn with_easing<G: Easing<F>>(self, easing: G) -> Tween<T, F, G> {
+        Tween {
+            start: self.start,
+            end: self.end,
+            duration: self.duration,
+            delay: self.delay,
+            easing,
+            repeat: self.repeat,
+            yoyo: self.yoyo,
+            elapsed: self.elapsed,
+        }
+    }
+
+    /// Play again after the first play completes.
+    pub fn with_repeat(self, repeat: Repeat) -> Tween<T, F, E> {
+        Tween { repeat, ..self }
+    }
+
+    /// When repeating, alternate between playing forwards and backwards.
+    pub fn with_yoyo(self, yoyo: bool) -> Tween<T, F, E> {
+        Tween { yoyo, ..self }
+    }
+
+    /// The value at the start of the tween.
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    /// The value at the end of the first play.
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    /// The duration of a single play, excluding the delay.
+    pub fn duration(&self) -> F {
+        self.duration
+    }
+
+    /// The delay before the first play.
+    pub fn delay(&self) -> F {
+        self.delay
+    }
+
+    /// The time from the start of the delay to the end of the last play, or `None` if the
+    /// tween repeats forever.
+    pub fn total_duration(&self) -> Option<F> {
+        match self.repeat {
+            Repeat::Times(repeats) => {
+                let plays = F::from(repeats).expect("repeat count is representable") + F::one();
+                Some(self.delay + self.duration.max(F::zero()) * plays)
+            }
+            Repeat::Forever => None,
+        }
+    }
+
+    /// The time which has passed, per [`Tween::advance`].
+    pub fn elapsed(&self) -> F {
+        self.elapsed
+    }
+
+    /// Advance the tween by `dt`, returning its new value.
+    pub fn advance(&mut self, dt: F) -> T {
+        self.elapsed = self.elapsed + dt;
+        self.value_at(self.elapsed)
+    }
+
+    /// Restart the tween from the beginning of its delay.
+    pub fn reset(&mut self) {
+        self.elapsed = F::zero();
+    }
+
+    /// Whether the tween's last play has completed.
+    ///
+    /// This is never true for tweens which repeat forever.
+    pub fn is_finished(&self) -> bool {
+        matches!(self.total_duration(), Some(total) if self.elapsed >= total)
+    }
+
+    /// The value of the tween at `time`, measured from the start of its delay.
+    ///
+    /// This does not depend on or change the elapsed time.
+    pub fn value_at(&self, time: F) -> T {
+        if let Some(total) = self.total_duration() {
+            if time >= total {
+                return self.start.lerp_bounded(self.end, self.final_progress());
+            }
+        }
+        let local = time - self.delay;
+        if local <= F::zero() {
+            return self.start;
+        }
+        if self.duration <= F::zero() {
+            // only reachable when repeating forever
+            return self.end;
+        }
+
+        let position = local / self.duration;
+        let play = position.floor();
+        let mut progress = position - play;
+        if self.yoyo && is_odd(play) {
+            progress = F::one() - progress;
+        }
+        self.start.lerp(self.end, self.easing.ease(progress))
+    }
+
+    /// The progress at which a finite tween ends: `0.0` if it yoyos back to the start.
+    fn final_progress(&self) -> F {
+        match self.repeat {
+            Repeat::Times(repeats) if self.yoyo && repeats % 2 == 1 => F::zero(),
+            _ => F::one(),
+        }
+    }
+}
+
+fn is_odd<F: Float>(play: F) -> bool {
+    let two = constant::<F>(2.0);
+    play - two * (play / two).floor() == F::one()
+}
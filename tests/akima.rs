@@ -0,0 +1,93 @@
+use common::close;
+use lerp::{AkimaSpline, CubicSpline, SplineBoundary, SplineError};
+
+mod common;
+
+#[test]
+fn errors() {
+    assert_eq!(
+        AkimaSpline::new(vec![0.0, 1.0], vec![1.0])
+            .unwrap_err()
+            .to_string(),
+        "slice length mismatch: expected 2, found 1"
+    );
+    assert_eq!(
+        AkimaSpline::<f64>::new(Vec::new(), Vec::new()),
+        Err(SplineError::TooFewPoints)
+    );
+}
+
+#[test]
+fn passes_through_knots() {
+    let xs = vec![0.0, 0.5, 1.5, 2.0, 3.5, 4.0];
+    let ys = vec![2.0, -1.0, 0.0, 4.0, 4.5, 1.0];
+    let spline = AkimaSpline::new(xs.clone(), ys.clone()).unwrap();
+    for (&x, &y) in xs.iter().zip(&ys) {
+        assert!(close(spline.eval(x), y));
+    }
+}
+
+#[test]
+fn outliers_stay_local() {
+    let xs: Vec<f64> = (0..12).map(f64::from).collect();
+    let mut ys = vec![1.0; 12];
+    ys[6] = 10.0;
+    let akima = AkimaSpline::new(xs.clone(), ys.clone()).unwrap();
+    let natural = CubicSpline::new(xs, ys, SplineBoundary::Natural).unwrap();
+
+    let far: Vec<f64> = (0..=30).map(|i| f64::from(i) / 10.0).collect();
+    assert!(far.iter().all(|&x| akima.eval(x) == 1.0));
+    assert!(far.iter().any(|&x| (natural.eval(x) - 1.0).abs() > 1e-3));
+}
+
+#[test]
+fn follows_collinear_knots() {
+    let xs = vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+    let ys = vec![0.0, 1.0, 2.0, 3.0, 0.0, 0.0];
+    let spline = AkimaSpline::new(xs, ys).unwrap();
+    for &x in &[0.25, 0.5, 1.0, 1.5, 1.75] {
+        assert!(close(spline.eval(x), x));
+        assert!(close(spline.derivative(x), 1.0));
+    }
+}
+
+#[test]
+fn reproduces_lines() {
+    let xs = vec![-2.0, 0.0, 0.5, 3.0];
+    let ys: Vec<f64> = xs.iter().map(|x| 0.5 * x + 2.0).collect();
+    let spline = AkimaSpline::new(xs, ys).unwrap();
+    for &x in &[-4.0, -1.0, 0.25, 2.0, 5.0] {
+        assert!(close(spline.eval(x), 0.5 * x + 2.0));
+    }
+}
+
+#[test]
+fn few_points() {
+    let line = AkimaSpline::new(vec![0.0, 2.0], vec![1.0, 5.0]).unwrap();
+    assert!(close(line.eval(0.5), 2.0));
+
+    let three = AkimaSpline::new(vec![0.0, 1.0, 2.0], vec![0.0, 1.0, 0.0]).unwrap();
+    assert!(close(three.eval(1.0), 1.0));
+    assert!(three.eval(0.5).is_finite());
+}
+
+#[test]
+fn extrapolation() {
+    let xs = vec![0.0, 1.0, 2.0, 3.0];
+    let ys = vec![0.0, 1.0, 2.0, 3.0];
+    let spline = AkimaSpline::new(xs, ys).unwrap();
+    assert!(close(spline.eval(5.0), 5.0));
+    assert_eq!(spline.eval_bounded(5.0), 3.0);
+    assert_eq!(spline.eval_bounded(-5.0), 0.0);
+}
+
+#[test]
+fn continuous_first_derivative() {
+    let xs = vec![0.0, 0.5, 1.5, 2.0, 3.5, 4.0];
+    let ys = vec![2.0, -1.0, 0.0, 4.0, 4.5, 1.0];
+    let spline = AkimaSpline::new(xs.clone(), ys).unwrap();
+    for &x in &xs[1..xs.len() - 1] {
+        let difference: f64 = spline.derivative(x - 1e-9) - spline.derivative(x);
+        assert!(difference.abs() < 1e-6);
+    }
+}
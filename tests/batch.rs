@@ -0,0 +1,72 @@
+use lerp::{LengthMismatch, Lerp, LerpBatch};
+
+// deterministic pseudo-random values, so that lanes see varied inputs
+fn values(seed: u32, len: usize) -> Vec<f64> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            (state as f64 / u32::MAX as f64) * 200.0 - 100.0
+        })
+        .collect()
+}
+
+#[test]
+fn f64_matches_lerp() {
+    // odd lengths exercise both the vectorized body and the scalar remainder
+    for &len in &[0, 1, 3, 4, 5, 17, 1000] {
+        let a = values(1, len);
+        let b = values(2, len);
+        let t = values(3, len);
+
+        let mut out = vec![0.0; len];
+        f64::lerp_batch(&a, &b, 0.37, &mut out).unwrap();
+        for i in 0..len {
+            assert_eq!(out[i].to_bits(), a[i].lerp(b[i], 0.37).to_bits());
+        }
+
+        f64::lerp_batch_each(&a, &b, &t, &mut out).unwrap();
+        for i in 0..len {
+            assert_eq!(out[i].to_bits(), a[i].lerp(b[i], t[i]).to_bits());
+        }
+    }
+}
+
+#[test]
+fn f32_matches_lerp() {
+    for &len in &[0, 1, 7, 8, 9, 33, 1000] {
+        let a: Vec<f32> = values(4, len).into_iter().map(|v| v as f32).collect();
+        let b: Vec<f32> = values(5, len).into_iter().map(|v| v as f32).collect();
+        let t: Vec<f32> = values(6, len).into_iter().map(|v| v as f32).collect();
+
+        let mut out = vec![0.0; len];
+        f32::lerp_batch(&a, &b, -1.5, &mut out).unwrap();
+        for i in 0..len {
+            assert_eq!(out[i].to_bits(), a[i].lerp(b[i], -1.5).to_bits());
+        }
+
+        f32::lerp_batch_each(&a, &b, &t, &mut out).unwrap();
+        for i in 0..len {
+            assert_eq!(out[i].to_bits(), a[i].lerp(b[i], t[i]).to_bits());
+        }
+    }
+}
+
+#[test]
+fn length_mismatch() {
+    let mut out = [0.0_f32; 9];
+    assert_eq!(
+        f32::lerp_batch(&[0.0; 9], &[0.0; 9], 0.5, &mut out[..8]),
+        Err(LengthMismatch {
+            expected: 9,
+            actual: 8
+        })
+    );
+    assert_eq!(
+        f32::lerp_batch_each(&[0.0; 9], &[0.0; 9], &[0.0; 10], &mut out),
+        Err(LengthMismatch {
+            expected: 9,
+            actual: 10
+        })
+    );
+}
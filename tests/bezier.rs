@@ -0,0 +1,160 @@
+use lerp::{Bezier, CubicBezier, Curve, CurveIter, LinearBezier, QuadBezier, Vector3};
+
+fn close(a: Vector3<f64>, b: Vector3<f64>) -> bool {
+    (a - b).length() < 1e-12
+}
+
+fn cubic() -> CubicBezier<Vector3<f64>> {
+    CubicBezier::new(
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 2.0, 0.0),
+        Vector3::new(3.0, -1.0, 1.0),
+        Vector3::new(4.0, 0.0, 2.0),
+    )
+}
+
+#[test]
+fn endpoints_exact() {
+    let curve = cubic();
+    assert_eq!(curve.eval(0.0), curve.p0);
+    assert_eq!(curve.eval(1.0), curve.p3);
+
+    let quad = QuadBezier::new(1.5, -2.0, 7.25);
+    assert_eq!(quad.eval(0.0), 1.5);
+    assert_eq!(quad.eval(1.0), 7.25);
+}
+
+#[test]
+fn matches_bernstein_form() {
+    let c = cubic();
+    for &t in &[0.1, 0.3, 0.5, 0.8] {
+        let s = 1.0 - t;
+        let expected = c.p0 * (s * s * s)
+            + c.p1 * (3.0 * s * s * t)
+            + c.p2 * (3.0 * s * t * t)
+            + c.p3 * (t * t * t);
+        assert!(close(c.eval(t), expected));
+    }
+}
+
+#[test]
+fn general_matches_fixed_degree() {
+    let c = cubic();
+    let general = Bezier::new(vec![c.p0, c.p1, c.p2, c.p3]).unwrap();
+    assert_eq!(general.degree(), 3);
+    for &t in &[0.0, 0.25, 0.5, 0.75, 1.0, 1.5] {
+        assert!(close(general.eval(t), c.eval(t)));
+    }
+
+    let q = QuadBezier::new(0.0, 3.0, 1.0);
+    let general = Bezier::new(vec![0.0, 3.0, 1.0]).unwrap();
+    assert_eq!(general.eval(0.3), q.eval(0.3));
+}
+
+#[test]
+fn empty_bezier() {
+    assert!(Bezier::<f64>::new(Vec::new()).is_none());
+}
+
+#[test]
+fn constant_bezier() {
+    let point = Bezier::new(vec![4.0]).unwrap();
+    assert_eq!(point.degree(), 0);
+    assert_eq!(point.eval(0.7), 4.0);
+    assert!(point.derivative().is_none());
+    assert_eq!(point.split(0.5), (point.clone(), point.clone()));
+}
+
+#[test]
+fn split_traces_same_path() {
+    let c = cubic();
+    let t = 0.3;
+    let (left, right) = c.split(t);
+    assert_eq!(left.p0, c.p0);
+    assert_eq!(right.p3, c.p3);
+    assert_eq!(left.p3, right.p0);
+    for &u in &[0.0, 0.2, 0.5, 0.9, 1.0] {
+        assert!(close(left.eval(u), c.eval(u * t)));
+        assert!(close(right.eval(u), c.eval(t + u * (1.0 - t))));
+    }
+
+    let general = Bezier::new(vec![c.p0, c.p1, c.p2, c.p3]).unwrap();
+    let (gleft, gright) = general.split(t);
+    for (a, b) in gleft
+        .points()
+        .iter()
+        .zip(&[left.p0, left.p1, left.p2, left.p3])
+    {
+        assert!(close(*a, *b));
+    }
+    for (a, b) in gright
+        .points()
+        .iter()
+        .zip(&[right.p0, right.p1, right.p2, right.p3])
+    {
+        assert!(close(*a, *b));
+    }
+}
+
+#[test]
+fn derivative_matches_finite_difference() {
+    let c = cubic();
+    let d = c.derivative();
+    let general = Bezier::new(vec![c.p0, c.p1, c.p2, c.p3]).unwrap();
+    let gd = general.derivative().unwrap();
+    let h = 1e-6;
+    for &t in &[0.1, 0.5, 0.9] {
+        let numeric = (c.eval(t + h) - c.eval(t - h)) * (0.5 / h);
+        assert!((d.eval(t) - numeric).length() < 1e-6);
+        assert!(close(gd.eval(t), d.eval(t)));
+    }
+
+    let second = d.derivative();
+    assert_eq!(second, LinearBezier::new(second.p0, second.p1));
+    let third = second.derivative();
+    assert!(close(third, (c.p3 - c.p2 * 3.0 + c.p1 * 3.0 - c.p0) * 6.0));
+}
+
+#[test]
+fn high_degree() {
+    // the curve with control points `i / n` is the straight line `t`, at any degree
+    for &n in &[7_usize, 8, 9, 20] {
+        let points: Vec<f64> = (0..=n).map(|i| i as f64 / n as f64).collect();
+        let curve = Bezier::new(points).unwrap();
+        for &t in &[0.0, 0.2, 0.5, 0.9, 1.0] {
+            assert!((curve.eval(t) - t).abs() < 1e-12);
+            let (left, right) = curve.split(t);
+            assert!((left.points()[n] - t).abs() < 1e-12);
+            assert!((right.points()[0] - t).abs() < 1e-12);
+        }
+        let derivative = curve.derivative().unwrap();
+        assert!(derivative.points().iter().all(|&p| (p - 1.0).abs() < 1e-12));
+    }
+}
+
+#[test]
+fn sample_iter() {
+    let line = LinearBezier::new(0.0, 8.0);
+    let points: Vec<f64> = line.sample_iter(4).collect();
+    assert_eq!(points, vec![0.0, 2.0, 4.0, 6.0]);
+    let points: Vec<f64> = line.sample_iter_closed(5).collect();
+    assert_eq!(points, vec![0.0, 2.0, 4.0, 6.0, 8.0]);
+}
+
+#[test]
+fn sample_iter_degenerate() {
+    let line = LinearBezier::new(0.0, 8.0);
+    assert_eq!(line.sample_iter(0).count(), 0);
+    assert_eq!(line.sample_iter_closed(0).count(), 0);
+    assert_eq!(line.sample_iter_closed(1).collect::<Vec<f64>>(), vec![8.0]);
+}
+
+#[test]
+fn sample_iter_exact_size() {
+    let c = cubic();
+    let mut iter = c.sample_iter_closed(10);
+    assert_eq!(iter.len(), 10);
+    iter.next();
+    assert_eq!(iter.len(), 9);
+    assert_eq!(iter.last(), Some(c.p3));
+}
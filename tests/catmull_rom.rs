@@ -0,0 +1,131 @@
+use common::close;
+use lerp::{CatmullRom, CatmullRomKind, Curve, CurveIter, Vector3};
+
+mod common;
+
+fn waypoints() -> Vec<Vector3<f64>> {
+    vec![
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(1.1, 0.1, 0.0),
+        Vector3::new(5.0, 4.0, 1.0),
+        Vector3::new(6.0, 0.0, -2.0),
+    ]
+}
+
+const KINDS: [CatmullRomKind; 3] = [
+    CatmullRomKind::Uniform,
+    CatmullRomKind::Centripetal,
+    CatmullRomKind::Chordal,
+];
+
+#[test]
+fn too_few_points() {
+    assert!(CatmullRom::<f64, f64>::uniform(Vec::new()).is_none());
+    assert!(CatmullRom::<f64, f64>::uniform(vec![1.0]).is_none());
+    assert!(CatmullRom::<f64, f64>::centripetal(vec![1.0]).is_none());
+}
+
+#[test]
+fn passes_through_control_points() {
+    let points = waypoints();
+    for &kind in &KINDS {
+        let spline = CatmullRom::new(points.clone(), kind).unwrap();
+        assert_eq!(spline.points(), &points[..]);
+        assert_eq!(spline.segment_count(), 4);
+        for (index, &point) in points.iter().enumerate() {
+            assert_eq!(spline.eval(index as f64 / 4.0), point);
+        }
+        let sampled: Vec<_> = spline.sample_iter_closed(5).collect();
+        assert_eq!(sampled, points);
+    }
+}
+
+#[test]
+fn uniform_matches_matrix_form() {
+    let points = [0.0, 2.0, -1.0, 3.0];
+    let spline = CatmullRom::uniform(points.to_vec()).unwrap();
+    let [p0, p1, p2, p3] = points;
+    for &t in &[0.1, 0.25, 0.5, 0.9] {
+        let expected = 0.5
+            * (2.0 * p1
+                + (-p0 + p2) * t
+                + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t * t
+                + (-p0 + 3.0 * p1 - 3.0 * p2 + p3) * t * t * t);
+        assert!(close(spline.segment(1, t).unwrap(), expected));
+    }
+}
+
+#[test]
+fn two_points_is_a_line() {
+    let spline = CatmullRom::uniform(vec![1.0, 5.0]).unwrap();
+    for &t in &[0.0, 0.25, 0.5, 0.75, 1.0] {
+        assert!(close(spline.eval(t), 1.0 + 4.0 * t));
+    }
+}
+
+#[test]
+fn smooth_at_joints() {
+    let h = 1e-6;
+    for &kind in &KINDS {
+        let spline = CatmullRom::new(waypoints(), kind).unwrap();
+        for index in 1..spline.segment_count() {
+            // the incoming and outgoing tangents share a direction
+            let incoming = spline.segment(index - 1, 1.0).unwrap()
+                - spline.segment(index - 1, 1.0 - h).unwrap();
+            let outgoing = spline.segment(index, h).unwrap() - spline.segment(index, 0.0).unwrap();
+            let cosine = incoming.normalize().dot(outgoing.normalize());
+            assert!((cosine - 1.0).abs() < 1e-5, "{:?}", kind);
+        }
+    }
+}
+
+#[test]
+fn centripetal_does_not_overshoot_tight_corner() {
+    // with a short segment between two long ones, the uniform spline loops past the
+    // corner, but the centripetal spline stays close to it
+    let points = vec![0.0, 10.0, 10.1, 20.0];
+    let uniform = CatmullRom::uniform(points.clone()).unwrap();
+    let centripetal = CatmullRom::centripetal(points).unwrap();
+    let uniform_max = uniform.sample_iter_closed(301).fold(f64::MIN, f64::max);
+    let centripetal_max = centripetal.sample_iter_closed(301).fold(f64::MIN, f64::max);
+    let uniform_min_middle = (0..=100)
+        .map(|i| uniform.segment(1, i as f64 / 100.0).unwrap())
+        .fold(f64::MAX, f64::min);
+    let centripetal_min_middle = (0..=100)
+        .map(|i| centripetal.segment(1, i as f64 / 100.0).unwrap())
+        .fold(f64::MAX, f64::min);
+    assert!(close(centripetal_max, 20.0));
+    assert!(uniform_max >= 20.0);
+    assert!(uniform_min_middle < 10.0);
+    assert!(centripetal_min_middle >= 10.0 - 1e-9);
+}
+
+#[test]
+fn coincident_points() {
+    let spline = CatmullRom::chordal(vec![0.0, 1.0, 1.0, 2.0]).unwrap();
+    for value in spline.sample_iter_closed(31) {
+        assert!(value.is_finite());
+    }
+}
+
+#[test]
+fn segment_out_of_bounds() {
+    let spline = CatmullRom::uniform(vec![0.0, 1.0, 2.0]).unwrap();
+    assert_eq!(spline.segment(1, 1.0), Some(2.0));
+    assert_eq!(spline.segment(2, 0.0), None);
+}
+
+#[test]
+fn extrapolation() {
+    let spline = CatmullRom::uniform(vec![0.0, 1.0, 2.0]).unwrap();
+    assert!(close(spline.eval(-0.5), -1.0));
+    assert!(close(spline.eval(1.5), 3.0));
+}
+
+#[test]
+fn half_open_sampling() {
+    let spline = CatmullRom::uniform(vec![0.0, 1.0, 2.0, 3.0]).unwrap();
+    let sampled: Vec<f64> = spline.sample_iter(3).collect();
+    assert_eq!(sampled, vec![0.0, 1.0, 2.0]);
+}
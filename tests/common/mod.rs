@@ -1,6 +1,13 @@
 use std::fmt::Debug;
 
 // Helper when working with floats to "round" them, so we can compare them better
+#[allow(dead_code)]
 pub fn round(d: &dyn Debug) -> String {
     format!("{:.1?}", d)
 }
+
+// Whether two floats are equal, up to rounding error
+#[allow(dead_code)]
+pub fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
@@ -0,0 +1,175 @@
+use common::close;
+use lerp::{CubicSpline, LengthMismatch, SplineBoundary, SplineError};
+
+mod common;
+
+fn cubic(x: f64) -> f64 {
+    0.5 * x * x * x - 2.0 * x * x + x + 3.0
+}
+
+fn cubic_slope(x: f64) -> f64 {
+    1.5 * x * x - 4.0 * x + 1.0
+}
+
+fn uneven_knots() -> Vec<f64> {
+    vec![-1.0, 0.0, 0.5, 2.0, 2.25, 4.0]
+}
+
+const BOUNDARIES: [SplineBoundary<f64>; 3] = [
+    SplineBoundary::Natural,
+    SplineBoundary::Clamped {
+        start: 0.5,
+        end: -1.0,
+    },
+    SplineBoundary::NotAKnot,
+];
+
+#[test]
+fn errors() {
+    assert_eq!(
+        CubicSpline::new(vec![0.0, 1.0], vec![0.0], SplineBoundary::Natural),
+        Err(SplineError::LengthMismatch(LengthMismatch {
+            expected: 2,
+            actual: 1
+        }))
+    );
+    assert_eq!(
+        CubicSpline::new(vec![0.0], vec![0.0], SplineBoundary::Natural),
+        Err(SplineError::TooFewPoints)
+    );
+    assert_eq!(
+        CubicSpline::new(vec![0.0, 1.0, 1.0], vec![0.0; 3], SplineBoundary::Natural),
+        Err(SplineError::NotIncreasing { index: 2 })
+    );
+    assert_eq!(
+        CubicSpline::new(
+            vec![0.0, f64::NAN, 2.0],
+            vec![0.0; 3],
+            SplineBoundary::Natural
+        ),
+        Err(SplineError::NotIncreasing { index: 1 })
+    );
+}
+
+#[test]
+fn passes_through_knots() {
+    let xs = uneven_knots();
+    let ys: Vec<f64> = xs.iter().map(|x| (x * 1.7).sin()).collect();
+    for &boundary in &BOUNDARIES {
+        let spline = CubicSpline::new(xs.clone(), ys.clone(), boundary).unwrap();
+        for (&x, &y) in xs.iter().zip(&ys) {
+            assert!(close(spline.eval(x), y), "{:?}", boundary);
+        }
+    }
+}
+
+#[test]
+fn continuous_second_derivative() {
+    let xs = uneven_knots();
+    let ys: Vec<f64> = xs.iter().map(|x| (x * 1.7).sin()).collect();
+    let h = 1e-9;
+    for &boundary in &BOUNDARIES {
+        let spline = CubicSpline::new(xs.clone(), ys.clone(), boundary).unwrap();
+        for &x in &xs[1..xs.len() - 1] {
+            assert!((spline.derivative(x - h) - spline.derivative(x)).abs() < 1e-6);
+            assert!(
+                (spline.second_derivative(x - h) - spline.second_derivative(x)).abs() < 1e-6,
+                "{:?} at {}",
+                boundary,
+                x
+            );
+        }
+    }
+}
+
+#[test]
+fn natural_boundary() {
+    let xs = uneven_knots();
+    let ys: Vec<f64> = xs.iter().map(|x| x.exp()).collect();
+    let spline = CubicSpline::new(xs.clone(), ys, SplineBoundary::Natural).unwrap();
+    assert!(close(spline.second_derivative(xs[0]), 0.0));
+    assert!(spline.second_derivative(xs[xs.len() - 1] - 1e-12).abs() < 1e-6);
+}
+
+#[test]
+fn natural_reproduces_lines() {
+    let xs = uneven_knots();
+    let ys: Vec<f64> = xs.iter().map(|x| 3.0 * x - 1.0).collect();
+    let spline = CubicSpline::new(xs, ys, SplineBoundary::Natural).unwrap();
+    for &x in &[-3.0, -0.3, 1.0, 3.9, 7.0] {
+        assert!(close(spline.eval(x), 3.0 * x - 1.0));
+        assert!(close(spline.derivative(x), 3.0));
+    }
+}
+
+#[test]
+fn clamped_reproduces_cubics() {
+    let xs = uneven_knots();
+    let ys: Vec<f64> = xs.iter().map(|&x| cubic(x)).collect();
+    let boundary = SplineBoundary::Clamped {
+        start: cubic_slope(xs[0]),
+        end: cubic_slope(xs[xs.len() - 1]),
+    };
+    let spline = CubicSpline::new(xs, ys, boundary).unwrap();
+    for &x in &[-1.0, -0.7, 0.25, 1.0, 3.0, 4.0] {
+        assert!(close(spline.eval(x), cubic(x)));
+        assert!(close(spline.derivative(x), cubic_slope(x)));
+    }
+}
+
+#[test]
+fn not_a_knot_reproduces_cubics() {
+    let xs = uneven_knots();
+    let ys: Vec<f64> = xs.iter().map(|&x| cubic(x)).collect();
+    let spline = CubicSpline::new(xs, ys, SplineBoundary::NotAKnot).unwrap();
+    for &x in &[-2.0, -0.7, 0.25, 1.0, 3.0, 5.0] {
+        assert!(close(spline.eval(x), cubic(x)));
+        assert!(close(spline.derivative(x), cubic_slope(x)));
+        assert!(close(spline.second_derivative(x), 3.0 * x - 4.0));
+    }
+}
+
+#[test]
+fn not_a_knot_few_points() {
+    let parabola = CubicSpline::new(
+        vec![0.0, 1.0, 3.0],
+        vec![0.0, 1.0, 9.0],
+        SplineBoundary::NotAKnot,
+    )
+    .unwrap();
+    for &x in &[-1.0, 0.5, 2.0, 4.0] {
+        assert!(close(parabola.eval(x), x * x));
+    }
+
+    let line = CubicSpline::new(vec![1.0, 3.0], vec![2.0, 6.0], SplineBoundary::NotAKnot).unwrap();
+    assert!(close(line.eval(2.5), 5.0));
+}
+
+#[test]
+fn two_point_clamped() {
+    let spline = CubicSpline::new(
+        vec![0.0, 1.0],
+        vec![0.0, 1.0],
+        SplineBoundary::Clamped {
+            start: 0.0,
+            end: 0.0,
+        },
+    )
+    .unwrap();
+    assert_eq!(spline.slopes(), &[0.0, 0.0]);
+    assert!(close(spline.eval(0.25), 0.15625));
+}
+
+#[test]
+fn bounded() {
+    let spline = CubicSpline::new(
+        vec![0.0, 1.0, 2.0],
+        vec![1.0, 2.0, 0.0],
+        SplineBoundary::Natural,
+    )
+    .unwrap();
+    assert_eq!(spline.eval_bounded(-5.0), 1.0);
+    assert_eq!(spline.eval_bounded(5.0), 0.0);
+    assert_eq!(spline.eval_bounded(1.5), spline.eval(1.5));
+    assert_ne!(spline.eval(5.0), 0.0);
+}
@@ -0,0 +1,90 @@
+use common::close;
+use lerp::{damp, damp_rate, Lerp, Vector3};
+
+mod common;
+
+/// Damp towards `target` for one second at `fps` frames per second.
+fn one_second(fps: u32, half_life: f64) -> f64 {
+    let dt = 1.0 / f64::from(fps);
+    let mut value = 0.0;
+    for _ in 0..fps {
+        value.lerp_to_dt(10.0, half_life, dt);
+    }
+    value
+}
+
+#[test]
+fn independent_of_frame_rate() {
+    let at_30 = one_second(30, 0.25);
+    let at_144 = one_second(144, 0.25);
+    assert!(close(at_30, at_144));
+    // four half-lives leave a sixteenth of the distance
+    assert!(close(at_30, 10.0 * (1.0 - 1.0 / 16.0)));
+}
+
+#[test]
+fn constant_t_depends_on_frame_rate() {
+    let run = |frames: u32| {
+        let mut value = 0.0_f64;
+        for _ in 0..frames {
+            value.lerp_to(10.0, 0.1);
+        }
+        value
+    };
+    assert!(!close(run(30), run(144)));
+}
+
+#[test]
+fn half_life() {
+    assert!(close(damp(2.0, 4.0, 0.5, 0.5), 3.0));
+    assert!(close(damp(2.0, 4.0, 0.5, 1.0), 3.5));
+}
+
+#[test]
+fn zero_dt_holds() {
+    assert_eq!(damp(2.0, 4.0, 0.5, 0.0), 2.0);
+    assert_eq!(damp_rate(2.0, 4.0, 3.0, 0.0), 2.0);
+}
+
+#[test]
+fn zero_half_life_snaps() {
+    assert_eq!(damp(2.0, 4.0, 0.0, 0.1), 4.0);
+    assert_eq!(damp(2.0, 4.0, 0.0, 0.0), 4.0);
+    assert_eq!(damp(2.0, 4.0, -1.0, 0.1), 4.0);
+}
+
+#[test]
+fn zero_rate_holds() {
+    assert_eq!(damp_rate(2.0, 4.0, 0.0, 10.0), 2.0);
+}
+
+#[test]
+fn rate_matches_half_life() {
+    let half_life = 0.3;
+    let rate = std::f64::consts::LN_2 / half_life;
+    for &dt in &[0.001, 0.1, 1.0, 5.0] {
+        assert!(close(
+            damp(1.0, 5.0, half_life, dt),
+            damp_rate(1.0, 5.0, rate, dt)
+        ));
+    }
+}
+
+#[test]
+fn tiny_steps_are_precise() {
+    let value = damp_rate(0.0_f64, 1.0, 1.0, 1e-12);
+    assert!((value - 1e-12).abs() < 1e-24);
+}
+
+#[test]
+fn vectors() {
+    let start = Vector3::new(0.0, 0.0, 0.0);
+    let target = Vector3::new(2.0, -4.0, 8.0);
+    assert_eq!(damp(start, target, 1.0, 1.0), Vector3::new(1.0, -2.0, 4.0));
+}
+
+#[test]
+fn f32() {
+    let value = damp(0.0_f32, 8.0, 1.0, 1.0);
+    assert!((value - 4.0).abs() < 1e-6);
+}
@@ -199,4 +199,111 @@ fn nested_manual_impl() {
             b: 0.1
         })
     );
-}
\ No newline at end of file
+}
+
+#[test]
+fn array_fields() {
+    #[derive(PartialEq, Debug, Lerp)]
+    struct Data {
+        position: [f64; 3],
+        color: [f32; 4],
+    }
+
+    assert_eq!(
+        round(
+            &Data {
+                position: [0.0, 1.0, 2.0],
+                color: [0.0, 0.0, 0.0, 1.0]
+            }
+            .lerp(
+                Data {
+                    position: [1.0, 0.0, 4.0],
+                    color: [1.0, 0.5, 0.0, 1.0]
+                },
+                0.5
+            )
+        ),
+        round(&Data {
+            position: [0.5, 0.5, 3.0],
+            color: [0.5, 0.25, 0.0, 1.0]
+        })
+    );
+}
+
+#[test]
+fn tuple_fields() {
+    #[derive(PartialEq, Debug, Lerp)]
+    struct Data((f64, f32), (f32,));
+
+    assert_eq!(
+        round(&Data((0.0, 1.0), (2.0,)).lerp(Data((1.0, 0.0), (4.0,)), 0.9)),
+        round(&Data((0.9, 0.1), (3.8,)))
+    );
+}
+
+#[test]
+fn nested_array_tuple_fields() {
+    #[derive(PartialEq, Debug, Lerp)]
+    struct InternalData(f64, f32);
+
+    #[derive(PartialEq, Debug, Lerp)]
+    struct Data {
+        a: [(f32, InternalData); 2],
+        b: [[f64; 2]; 2],
+    }
+
+    assert_eq!(
+        round(
+            &Data {
+                a: [(0.0, InternalData(0.0, 1.0)), (1.0, InternalData(2.0, 2.0))],
+                b: [[0.0, 1.0], [2.0, 3.0]]
+            }
+            .lerp(
+                Data {
+                    a: [(1.0, InternalData(1.0, 0.0)), (0.0, InternalData(4.0, 0.0))],
+                    b: [[1.0, 0.0], [4.0, 5.0]]
+                },
+                0.5
+            )
+        ),
+        round(&Data {
+            a: [(0.5, InternalData(0.5, 0.5)), (0.5, InternalData(3.0, 1.0))],
+            b: [[0.5, 0.5], [3.0, 4.0]]
+        })
+    );
+}
+
+#[test]
+fn array_type_override() {
+    #[derive(PartialEq, Debug)]
+    struct InternalData(f32);
+
+    impl Lerp<f32> for InternalData {
+        fn lerp(self, other: Self, t: f32) -> Self {
+            Self(self.0.lerp(other.0, t))
+        }
+    }
+
+    #[derive(PartialEq, Debug, Lerp)]
+    struct Data {
+        #[lerp(f32)]
+        a: [InternalData; 2],
+    }
+
+    assert_eq!(
+        round(
+            &Data {
+                a: [InternalData(0.0), InternalData(1.0)]
+            }
+            .lerp(
+                Data {
+                    a: [InternalData(1.0), InternalData(0.0)]
+                },
+                0.9
+            )
+        ),
+        round(&Data {
+            a: [InternalData(0.9), InternalData(0.1)]
+        })
+    );
+}
@@ -0,0 +1,145 @@
+use lerp::easing::{self, ease_lerp, Easing};
+
+type EasingFn = fn(f64) -> f64;
+
+const FAMILIES: [(&str, EasingFn, EasingFn, EasingFn); 10] = [
+    (
+        "quad",
+        easing::quad_in,
+        easing::quad_out,
+        easing::quad_in_out,
+    ),
+    (
+        "cubic",
+        easing::cubic_in,
+        easing::cubic_out,
+        easing::cubic_in_out,
+    ),
+    (
+        "quart",
+        easing::quart_in,
+        easing::quart_out,
+        easing::quart_in_out,
+    ),
+    (
+        "quint",
+        easing::quint_in,
+        easing::quint_out,
+        easing::quint_in_out,
+    ),
+    (
+        "sine",
+        easing::sine_in,
+        easing::sine_out,
+        easing::sine_in_out,
+    ),
+    (
+        "expo",
+        easing::expo_in,
+        easing::expo_out,
+        easing::expo_in_out,
+    ),
+    (
+        "circ",
+        easing::circ_in,
+        easing::circ_out,
+        easing::circ_in_out,
+    ),
+    (
+        "back",
+        easing::back_in,
+        easing::back_out,
+        easing::back_in_out,
+    ),
+    (
+        "elastic",
+        easing::elastic_in,
+        easing::elastic_out,
+        easing::elastic_in_out,
+    ),
+    (
+        "bounce",
+        easing::bounce_in,
+        easing::bounce_out,
+        easing::bounce_in_out,
+    ),
+];
+
+const EPSILON: f64 = 1e-9;
+
+#[test]
+fn endpoints() {
+    for &(name, ease_in, ease_out, ease_in_out) in &FAMILIES {
+        for &ease in &[ease_in, ease_out, ease_in_out] {
+            assert!(ease(0.0).abs() < EPSILON, "{}", name);
+            assert!((ease(1.0) - 1.0).abs() < EPSILON, "{}", name);
+        }
+    }
+}
+
+#[test]
+fn out_mirrors_in() {
+    for &(name, ease_in, ease_out, _) in &FAMILIES {
+        for i in 0..=20 {
+            let t = i as f64 / 20.0;
+            assert!(
+                (ease_out(t) - (1.0 - ease_in(1.0 - t))).abs() < 1e-3,
+                "{} {}",
+                name,
+                t
+            );
+        }
+    }
+}
+
+#[test]
+fn in_out_is_symmetric() {
+    for &(name, _, _, ease_in_out) in &FAMILIES {
+        assert!((ease_in_out(0.5) - 0.5).abs() < 1e-3, "{}", name);
+        for i in 0..=20 {
+            let t = i as f64 / 20.0;
+            assert!(
+                (ease_in_out(t) - (1.0 - ease_in_out(1.0 - t))).abs() < 1e-3,
+                "{} {}",
+                name,
+                t
+            );
+        }
+    }
+}
+
+#[test]
+fn in_out_is_continuous() {
+    for &(name, _, _, ease_in_out) in &FAMILIES {
+        let below = ease_in_out(0.5 - 1e-9);
+        let above = ease_in_out(0.5 + 1e-9);
+        assert!((below - above).abs() < 1e-4, "{}", name);
+    }
+}
+
+#[test]
+fn known_values() {
+    assert_eq!(easing::quad_in(0.5), 0.25);
+    assert_eq!(easing::cubic_out(0.5), 0.875);
+    assert_eq!(easing::quart_in_out(0.25), 0.03125);
+    assert!((easing::sine_in_out(0.25_f64) - 0.146_446_609_4).abs() < 1e-9);
+    assert!(easing::back_in(0.2_f64) < 0.0);
+    assert!(easing::back_out(0.8_f64) > 1.0);
+    assert!((easing::bounce_out(0.5_f64) - 0.765_625).abs() < 1e-9);
+}
+
+#[test]
+fn interchangeable() {
+    fn boxed(name: &str) -> Box<dyn Fn(f32) -> f32> {
+        match name {
+            "linear" => Box::new(easing::linear),
+            "steps" => Box::new(|t: f32| (t * 4.0).floor() / 4.0),
+            _ => Box::new(easing::expo_in_out),
+        }
+    }
+
+    assert_eq!(ease_lerp(0.0_f32, 8.0, 0.5, boxed("linear")), 4.0);
+    assert_eq!(ease_lerp(0.0_f32, 8.0, 0.4, boxed("steps")), 2.0);
+    assert_eq!(ease_lerp(0.0_f32, 8.0, 1.0, boxed("expo")), 8.0);
+    assert_eq!(boxed("steps").ease(0.6), 0.5);
+}
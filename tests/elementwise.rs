@@ -0,0 +1,60 @@
+use lerp::{lerp_array, Elementwise, Floor, Lerp, LerpIter, Vector3};
+
+#[test]
+fn arrays() {
+    assert_eq!(
+        lerp_array([0.0, 1.0, 2.0], [2.0, 1.0, 0.0], 0.25),
+        [0.5, 1.0, 1.5]
+    );
+    assert_eq!(
+        Elementwise([0.0_f64; 3]).lerp(Elementwise([4.0, -4.0, 8.0]), 0.5),
+        Elementwise([2.0, -2.0, 4.0])
+    );
+    assert_eq!(lerp_array::<f64, f64, 0>([], [], 0.5), []);
+}
+
+#[test]
+fn tuples() {
+    let a = Elementwise((0.0, 10.0, Vector3::new(0.0, 0.0, 0.0)));
+    let b = Elementwise((1.0, 20.0, Vector3::new(2.0, 4.0, 6.0)));
+    assert_eq!(
+        a.lerp(b, 0.5),
+        Elementwise((0.5, 15.0, Vector3::new(1.0, 2.0, 3.0)))
+    );
+    assert_eq!(a.lerp_bounded(b, 2.0), b);
+}
+
+#[test]
+fn nested() {
+    let a = Elementwise([Elementwise((0.0, 1.0)), Elementwise((2.0, 3.0))]);
+    let b = Elementwise([Elementwise((2.0, 1.0)), Elementwise((0.0, 5.0))]);
+    assert_eq!(
+        a.lerp(b, 0.5),
+        Elementwise([Elementwise((1.0, 1.0)), Elementwise((1.0, 4.0))])
+    );
+}
+
+#[test]
+fn integers() {
+    assert_eq!(
+        Elementwise([0_i32, 10]).lerp(Elementwise([5, 0]), Floor(0.5)),
+        Elementwise([2, 5])
+    );
+}
+
+#[test]
+fn iterate() {
+    let steps: Vec<_> = Elementwise((0.0, Elementwise([0.0, 4.0])))
+        .lerp_iter(Elementwise((2.0, Elementwise([4.0, 0.0]))), 4)
+        .map(|Elementwise((a, b))| (a, b.0))
+        .collect();
+    assert_eq!(
+        steps,
+        vec![
+            (0.0, [0.0, 4.0]),
+            (0.5, [1.0, 3.0]),
+            (1.0, [2.0, 2.0]),
+            (1.5, [3.0, 1.0])
+        ]
+    );
+}
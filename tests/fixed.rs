@@ -0,0 +1,68 @@
+use lerp::{Fixed, Lerp};
+
+fn reference_u8(a: u8, b: u8, t: u8) -> u8 {
+    let (a, b, t) = (a as u32, b as u32, t as u32);
+    ((a * (255 - t) + b * t + 127) / 255) as u8
+}
+
+fn reference_u16(a: u16, b: u16, t: u16) -> u16 {
+    let (a, b, t) = (a as u64, b as u64, t as u64);
+    ((a * (65535 - t) + b * t + 32767) / 65535) as u16
+}
+
+#[test]
+fn u8_matches_reference() {
+    for a in 0..=255 {
+        for b in 0..=255 {
+            for &t in &[0, 1, 64, 127, 128, 200, 254, 255] {
+                assert_eq!(a.lerp(b, Fixed(t)), reference_u8(a, b, t));
+            }
+        }
+    }
+    for t in 0..=255 {
+        for &(a, b) in &[(0, 255), (255, 0), (17, 200), (99, 98)] {
+            assert_eq!(a.lerp(b, Fixed(t)), reference_u8(a, b, t));
+        }
+    }
+}
+
+#[test]
+fn u16_matches_reference() {
+    let samples = [0, 1, 255, 256, 12345, 32767, 32768, 65534, 65535];
+    for &a in &samples {
+        for &b in &samples {
+            for &t in &samples {
+                assert_eq!(a.lerp(b, Fixed(t)), reference_u16(a, b, t));
+            }
+        }
+    }
+}
+
+#[test]
+fn endpoints() {
+    for a in 0..=255_u8 {
+        for b in 0..=255_u8 {
+            assert_eq!(a.lerp(b, Fixed(0)), a);
+            assert_eq!(a.lerp(b, Fixed(255)), b);
+        }
+    }
+    assert_eq!(0_u16.lerp(u16::MAX, Fixed(0)), 0);
+    assert_eq!(0_u16.lerp(u16::MAX, Fixed(u16::MAX)), u16::MAX);
+}
+
+#[test]
+fn bounded_is_lerp() {
+    for t in 0..=255 {
+        assert_eq!(30_u8.lerp_bounded(220, Fixed(t)), 30_u8.lerp(220, Fixed(t)));
+    }
+    let mut value = 0_u16;
+    value.lerp_bounded_to(1000, Fixed(u16::MAX));
+    assert_eq!(value, 1000);
+}
+
+#[test]
+fn weight_arithmetic() {
+    assert_eq!(Fixed(255_u8) * Fixed(128), Fixed(128));
+    assert_eq!(Fixed(128_u8) * Fixed(128), Fixed(64));
+    assert_eq!(Fixed(200_u8) + Fixed(100), Fixed(255));
+}
@@ -0,0 +1,69 @@
+use lerp::LerpGeometric;
+
+fn close(a: f64, b: f64) -> bool {
+    ((a - b) / b).abs() < 1e-12
+}
+
+#[test]
+fn endpoints_exact() {
+    for &(a, b) in &[(1.0, 100.0), (3.7, 0.0123), (-2.0, -9.5), (20.0, 20_000.0)] {
+        assert_eq!(a.lerp_geometric(b, 0.0), Some(a));
+        assert_eq!(a.lerp_geometric(b, 1.0), Some(b));
+    }
+}
+
+#[test]
+fn equal_ratios() {
+    let a = 20.0_f64;
+    let b = 20_000.0;
+    let third = a.lerp_geometric(b, 1.0 / 3.0).unwrap();
+    let two_thirds = a.lerp_geometric(b, 2.0 / 3.0).unwrap();
+    assert!(close(third, 200.0));
+    assert!(close(two_thirds, 2000.0));
+    assert!(close(third / a, two_thirds / third));
+}
+
+#[test]
+fn extrapolation() {
+    assert!(close(10.0.lerp_geometric(100.0, -1.0).unwrap(), 1.0));
+    assert!(close(10.0.lerp_geometric(100.0, 3.0).unwrap(), 10_000.0));
+    assert_eq!(10.0.lerp_geometric_bounded(100.0, -1.0), Some(10.0));
+}
+
+#[test]
+fn negative_endpoints() {
+    assert!(close((-1.0).lerp_geometric(-100.0, 0.5).unwrap(), -10.0));
+    assert!(close((-100.0).lerp_geometric(-1.0, 0.5).unwrap(), -10.0));
+}
+
+#[test]
+fn undefined_endpoints() {
+    assert_eq!(0.0.lerp_geometric(1.0, 0.5), None);
+    assert_eq!(1.0.lerp_geometric(0.0, 0.5), None);
+    assert_eq!(1.0.lerp_geometric(-0.0, 0.5), None);
+    assert_eq!((-1.0).lerp_geometric(1.0, 0.5), None);
+    assert_eq!(1.0.lerp_geometric(f64::NAN, 0.5), None);
+    assert!(0.0.geom_iter(1.0, 4).is_none());
+    assert!(1.0.geom_iter_closed(-1.0, 4).is_none());
+}
+
+#[test]
+fn iterators() {
+    let half_open: Vec<f64> = 1.0.geom_iter(1000.0, 3).unwrap().collect();
+    assert_eq!(half_open.len(), 3);
+    assert_eq!(half_open[0], 1.0);
+    assert!(close(half_open[1], 10.0));
+    assert!(close(half_open[2], 100.0));
+
+    let closed: Vec<f64> = 1.0.geom_iter_closed(1000.0, 4).unwrap().collect();
+    assert_eq!(closed.len(), 4);
+    assert!(close(closed[2], 100.0));
+    assert_eq!(closed[3], 1000.0);
+
+    assert_eq!(
+        1.0.geom_iter_closed(1000.0, 1).unwrap().collect::<Vec<_>>(),
+        vec![1000.0]
+    );
+    assert_eq!(1.0.geom_iter_closed(1000.0, 0).unwrap().count(), 0);
+    assert_eq!(1.0.geom_iter(1000.0, 7).unwrap().len(), 7);
+}
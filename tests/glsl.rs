@@ -0,0 +1,72 @@
+use lerp::glsl::{clamp, fract, mix, mix_array, mix_components, smootherstep, smoothstep, step};
+use lerp::Vector3;
+
+#[test]
+fn mix_any_lerp() {
+    assert_eq!(mix(0.0_f32, 10.0, 0.3), 3.0);
+    assert_eq!(
+        mix(
+            Vector3::new(0.0, 2.0, 4.0),
+            Vector3::new(2.0, 2.0, 0.0),
+            0.5
+        ),
+        Vector3::new(1.0, 2.0, 2.0)
+    );
+    assert_eq!(
+        mix_array(
+            [Vector3::new(0.0, 1.0, 0.0), Vector3::new(2.0, 3.0, 0.0)],
+            [Vector3::new(2.0, 1.0, 0.0), Vector3::new(0.0, 3.0, 0.0)],
+            0.5
+        ),
+        [Vector3::new(1.0, 1.0, 0.0), Vector3::new(1.0, 3.0, 0.0)]
+    );
+    assert_eq!(
+        mix_components([0.0, 0.0, 0.0], [1.0, 2.0, 4.0], [1.0, 0.5, 0.25]),
+        [1.0, 1.0, 1.0]
+    );
+}
+
+#[test]
+fn step_edges() {
+    assert_eq!(step(1.0, 0.5), 0.0);
+    assert_eq!(step(1.0, 1.0), 1.0);
+    assert_eq!(step(1.0, 1.5), 1.0);
+}
+
+#[test]
+fn clamp_like_glsl() {
+    assert_eq!(clamp(5.0, 0.0, 1.0), 1.0);
+    assert_eq!(clamp(-5.0, 0.0, 1.0), 0.0);
+    assert_eq!(clamp(0.5, 0.0, 1.0), 0.5);
+    assert_eq!(clamp(0.5, 1.0, 0.0), 0.0);
+}
+
+#[test]
+fn fract_values() {
+    assert_eq!(fract(1.25), 0.25);
+    assert_eq!(fract(-0.25), 0.75);
+    assert_eq!(fract(3.0), 0.0);
+}
+
+#[test]
+fn smoothstep_shape() {
+    assert_eq!(smoothstep(0.0, 1.0, -1.0), 0.0);
+    assert_eq!(smoothstep(0.0, 1.0, 0.0), 0.0);
+    assert_eq!(smoothstep(0.0, 1.0, 0.5), 0.5);
+    assert_eq!(smoothstep(0.0, 1.0, 1.0), 1.0);
+    assert_eq!(smoothstep(0.0, 1.0, 2.0), 1.0);
+    // zero slope at the edges
+    assert!(smoothstep(0.0, 1.0, 1e-4) < 1e-7);
+    assert!(1.0 - smoothstep(0.0, 1.0, 1.0 - 1e-4) < 1e-7);
+    assert!(smootherstep(0.0, 1.0, 1e-3) < 1e-8);
+}
+
+#[test]
+fn degenerate_edges() {
+    assert_eq!(smoothstep(2.0, 2.0, 1.0), 0.0);
+    assert_eq!(smoothstep(2.0, 2.0, 2.0), 1.0);
+    assert_eq!(smootherstep(2.0, 2.0, 3.0), 1.0);
+    assert_eq!(smoothstep(1.0, 0.0, 0.0), 1.0);
+    assert_eq!(smoothstep(1.0, 0.0, 1.0), 0.0);
+    assert_eq!(smootherstep(1.0, 0.0, 0.5), 0.5);
+}
@@ -0,0 +1,56 @@
+use lerp::{hermite, hermite_derivative, CubicBezier, Curve, Vector3};
+
+fn close(a: Vector3<f64>, b: Vector3<f64>) -> bool {
+    (a - b).length() < 1e-9
+}
+
+fn key() -> [Vector3<f64>; 4] {
+    [
+        Vector3::new(0.0, 1.0, 2.0),
+        Vector3::new(3.0, 0.0, -1.0),
+        Vector3::new(4.0, -2.0, 0.5),
+        Vector3::new(-1.0, 1.0, 1.0),
+    ]
+}
+
+#[test]
+fn endpoints() {
+    let [p0, m0, p1, m1] = key();
+    assert_eq!(hermite(p0, m0, p1, m1, 0.0), p0);
+    assert_eq!(hermite(p0, m0, p1, m1, 1.0), p1);
+    assert_eq!(hermite_derivative(p0, m0, p1, m1, 0.0), m0);
+    assert_eq!(hermite_derivative(p0, m0, p1, m1, 1.0), m1);
+}
+
+#[test]
+fn matches_equivalent_bezier() {
+    let [p0, m0, p1, m1] = key();
+    let bezier = CubicBezier::new(p0, p0 + m0 * (1.0 / 3.0), p1 + m1 * (-1.0 / 3.0), p1);
+    let velocity = bezier.derivative();
+    for &t in &[0.1, 0.4, 0.5, 0.85, 1.3] {
+        assert!(close(hermite(p0, m0, p1, m1, t), bezier.eval(t)));
+        assert!(close(
+            hermite_derivative(p0, m0, p1, m1, t),
+            velocity.eval(t)
+        ));
+    }
+}
+
+#[test]
+fn derivative_matches_finite_difference() {
+    let [p0, m0, p1, m1] = key();
+    let h = 1e-6;
+    for &t in &[0.2, 0.5, 0.7] {
+        let numeric = (hermite(p0, m0, p1, m1, t + h) - hermite(p0, m0, p1, m1, t - h)) * (0.5 / h);
+        assert!((hermite_derivative(p0, m0, p1, m1, t) - numeric).length() < 1e-6);
+    }
+}
+
+#[test]
+fn linear_tangents_reproduce_lerp() {
+    for &t in &[0.0, 0.3, 0.5, 0.9] {
+        let value: f32 = hermite(2.0, 4.0, 6.0, 4.0, t);
+        assert!((value - (2.0 + 4.0 * t)).abs() < 1e-6);
+        assert!((hermite_derivative(2.0_f32, 4.0, 6.0, 4.0, t) - 4.0).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,100 @@
+use lerp::{Ceil, Floor, Lerp, NearestEven, TowardZero};
+
+#[test]
+fn rounding_modes() {
+    // 0 -> 7 at 0.5 is exactly 3.5; 0 -> -7 is exactly -3.5
+    assert_eq!(0_i32.lerp(7, Floor(0.5)), 3);
+    assert_eq!(0_i32.lerp(-7, Floor(0.5)), -4);
+    assert_eq!(0_i32.lerp(7, Ceil(0.5)), 4);
+    assert_eq!(0_i32.lerp(-7, Ceil(0.5)), -3);
+    assert_eq!(0_i32.lerp(7, NearestEven(0.5)), 4);
+    assert_eq!(0_i32.lerp(-7, NearestEven(0.5)), -4);
+    assert_eq!(0_i32.lerp(5, NearestEven(0.5)), 2);
+    assert_eq!(0_i32.lerp(-5, NearestEven(0.5)), -2);
+    assert_eq!(0_i32.lerp(7, TowardZero(0.5)), 3);
+    assert_eq!(0_i32.lerp(-7, TowardZero(0.5)), -3);
+}
+
+#[test]
+fn rounding_applies_to_result() {
+    // the fractional part is relative to the result, not to the distance travelled
+    assert_eq!(1_i32.lerp(2, NearestEven(0.5)), 2);
+    assert_eq!(2_i32.lerp(3, NearestEven(0.5)), 2);
+    assert_eq!((-1_i32).lerp(-2, NearestEven(0.5)), -2);
+    assert_eq!(1_i32.lerp(-2, TowardZero(0.5)), 0);
+    assert_eq!(1_i32.lerp(-2, Floor(0.5)), -1);
+}
+
+#[test]
+fn exact_endpoints() {
+    assert_eq!(i64::MIN.lerp(i64::MAX, Floor(0.0)), i64::MIN);
+    assert_eq!(i64::MIN.lerp(i64::MAX, Floor(1.0)), i64::MAX);
+    assert_eq!(u64::MAX.lerp(1, Ceil(1.0)), 1);
+    assert_eq!(
+        (i64::MAX - 1).lerp(i64::MAX, NearestEven(1.0_f32)),
+        i64::MAX
+    );
+}
+
+#[test]
+fn no_overflow_near_limits() {
+    assert_eq!(i8::MIN.lerp(i8::MAX, Floor(0.5)), -1);
+    assert_eq!(i8::MIN.lerp(i8::MAX, Ceil(0.5)), 0);
+    assert_eq!(u8::MAX.lerp(u8::MIN, NearestEven(0.5)), 128);
+    assert_eq!(u8::MAX.lerp(u8::MIN, TowardZero(0.5)), 127);
+    assert_eq!(i32::MAX.lerp(i32::MIN, Floor(0.5)), -1);
+    assert_eq!(u32::MAX.lerp(u32::MAX - 2, Floor(0.5)), u32::MAX - 1);
+    assert_eq!(i16::MIN.lerp(i16::MAX, Floor(0.25)), -16385);
+}
+
+#[test]
+fn extrapolation_saturates() {
+    assert_eq!(0_u8.lerp(200, Floor(2.0)), u8::MAX);
+    assert_eq!(100_u8.lerp(200, Floor(-2.0)), u8::MIN);
+    assert_eq!(0_i64.lerp(i64::MAX, Ceil(1e30)), i64::MAX);
+    assert_eq!(0_i64.lerp(i64::MAX, Ceil(-1e30)), i64::MIN);
+    assert_eq!(0_i16.lerp(10, Floor(f64::INFINITY)), i16::MAX);
+    assert_eq!(0_i16.lerp(10, Floor(f64::NEG_INFINITY)), i16::MIN);
+}
+
+#[test]
+fn bounded() {
+    assert_eq!(0_u8.lerp_bounded(200, Floor(2.0)), 200);
+    assert_eq!(100_u8.lerp_bounded(200, Floor(-2.0)), 100);
+    assert_eq!(i64::MIN.lerp_bounded(i64::MAX, Ceil(7.0)), i64::MAX);
+}
+
+#[test]
+fn mutating() {
+    let mut value = 10_usize;
+    value.lerp_to(20, NearestEven(0.25));
+    assert_eq!(value, 12);
+    value.lerp_bounded_to(0, TowardZero(4.0));
+    assert_eq!(value, 0);
+}
+
+#[test]
+fn equal_endpoints_with_infinite_t() {
+    assert_eq!(5_i16.lerp(5, Floor(f64::INFINITY)), 5);
+    assert_eq!(5_i16.lerp(5, Ceil(f64::NEG_INFINITY)), 5);
+    assert_eq!(0_i16.lerp(5, Floor(f64::INFINITY)), i16::MAX);
+    assert_eq!(0_i16.lerp(5, Floor(f64::NEG_INFINITY)), i16::MIN);
+}
+
+#[test]
+fn exact_at_64_bits() {
+    assert_eq!(i64::MIN.lerp(i64::MAX, Floor(0.5)), -1);
+    assert_eq!(i64::MIN.lerp(i64::MAX, Ceil(0.5)), 0);
+    // i64::MAX * (1.0 / 3.0) is 3074457345618258431.67..., using the float nearest a third
+    assert_eq!(
+        0_i64.lerp(i64::MAX, NearestEven(1.0 / 3.0)),
+        3074457345618258432
+    );
+    assert_eq!(0_i64.lerp(i64::MAX, Floor(1.0 / 3.0)), 3074457345618258431);
+    assert_eq!(
+        0_i64.lerp(-i64::MAX, TowardZero(1.0 / 3.0)),
+        -3074457345618258431
+    );
+    assert_eq!(u64::MAX.lerp(0, Floor(1e-300)), u64::MAX - 1);
+    assert_eq!(u64::MAX.lerp(0, Ceil(1e-300)), u64::MAX);
+}
@@ -0,0 +1,184 @@
+use lerp::{
+    interp, interp_slice, interp_with, Extrapolation, LengthMismatch, SplineError, Vector3,
+};
+
+const XS: [f64; 4] = [0.0, 1.0, 2.0, 4.0];
+const YS: [f64; 4] = [1.0, 3.0, 2.0, 6.0];
+
+#[test]
+fn knots_exact() {
+    for (&x, &y) in XS.iter().zip(&YS) {
+        assert_eq!(interp(x, &XS, &YS), Ok(y));
+    }
+}
+
+#[test]
+fn between_knots() {
+    assert_eq!(interp(0.5, &XS, &YS), Ok(2.0));
+    assert_eq!(interp(1.25, &XS, &YS), Ok(2.75));
+    assert_eq!(interp(3.0, &XS, &YS), Ok(4.0));
+}
+
+#[test]
+fn extrapolation() {
+    let clamp = Extrapolation::Clamp;
+    let extend = Extrapolation::Extend;
+    let fill = Extrapolation::Fill(f64::NAN);
+
+    assert_eq!(interp(-1.0, &XS, &YS), Ok(1.0));
+    assert_eq!(interp(5.0, &XS, &YS), Ok(6.0));
+    assert_eq!(interp_with(-1.0, &XS, &YS, clamp, extend), Ok(1.0));
+    assert_eq!(interp_with(5.0, &XS, &YS, clamp, extend), Ok(8.0));
+    assert_eq!(interp_with(-1.0, &XS, &YS, extend, clamp), Ok(-1.0));
+    assert!(interp_with(-1.0, &XS, &YS, fill, clamp).unwrap().is_nan());
+    assert!(interp_with(5.0, &XS, &YS, clamp, fill).unwrap().is_nan());
+
+    // the behavior only applies strictly outside the knots
+    assert_eq!(interp_with(0.0, &XS, &YS, fill, fill), Ok(1.0));
+    assert_eq!(interp_with(4.0, &XS, &YS, fill, fill), Ok(6.0));
+}
+
+#[test]
+fn single_knot() {
+    for &behavior in &[Extrapolation::Clamp, Extrapolation::Extend] {
+        assert_eq!(
+            interp_with(-1.0, &[2.0], &[7.0], behavior, behavior),
+            Ok(7.0)
+        );
+        assert_eq!(
+            interp_with(2.0, &[2.0], &[7.0], behavior, behavior),
+            Ok(7.0)
+        );
+        assert_eq!(
+            interp_with(3.0, &[2.0], &[7.0], behavior, behavior),
+            Ok(7.0)
+        );
+    }
+}
+
+#[test]
+fn repeated_knots() {
+    let xs = [0.0, 1.0, 1.0, 2.0];
+    let ys = [0.0, 1.0, 5.0, 6.0];
+    assert_eq!(interp(0.5, &xs, &ys), Ok(0.5));
+    assert_eq!(interp(1.0, &xs, &ys), Ok(5.0));
+    assert_eq!(interp(1.5, &xs, &ys), Ok(5.5));
+}
+
+#[test]
+fn repeated_end_knots() {
+    let clamp = Extrapolation::Clamp;
+    let extend = Extrapolation::Extend;
+
+    // continuous with the value at the last knot, which is its last appearance
+    assert_eq!(
+        interp_with(2.0, &[0.0, 1.0, 1.0], &[0.0, 1.0, 2.0], clamp, extend),
+        Ok(4.0)
+    );
+    assert_eq!(
+        interp_with(-1.0, &[0.0, 0.0, 1.0], &[5.0, 0.0, 1.0], extend, clamp),
+        Ok(-1.0)
+    );
+    assert_eq!(
+        interp_with(-1.0, &[0.0, 0.0, 1.0], &[5.0, 0.0, 1.0], clamp, clamp),
+        Ok(0.0)
+    );
+
+    // without a knot at a different x, there is no line to extend
+    for &x in &[-1.0, 3.0] {
+        assert_eq!(
+            interp_with(x, &[1.0, 1.0], &[2.0, 3.0], extend, extend),
+            Ok(3.0)
+        );
+    }
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        interp(0.0, &[0.0, 1.0], &[0.0]),
+        Err(SplineError::LengthMismatch(LengthMismatch {
+            expected: 2,
+            actual: 1
+        }))
+    );
+    assert_eq!(
+        interp::<f64, f64>(0.0, &[], &[]),
+        Err(SplineError::TooFewPoints)
+    );
+
+    let mut out = [0.0; 2];
+    assert_eq!(
+        interp_slice(
+            &[0.0, 1.0, 2.0],
+            &XS,
+            &YS,
+            Extrapolation::Clamp,
+            Extrapolation::Clamp,
+            &mut out
+        ),
+        Err(SplineError::LengthMismatch(LengthMismatch {
+            expected: 3,
+            actual: 2
+        }))
+    );
+    assert_eq!(out, [0.0; 2]);
+}
+
+#[test]
+fn batched_matches_single() {
+    let x: Vec<f64> = (-10..=50).map(|i| f64::from(i) / 10.0).collect();
+    let mut out = vec![0.0; x.len()];
+    let (left, right) = (Extrapolation::Extend, Extrapolation::Fill(-1.0));
+    interp_slice(&x, &XS, &YS, left, right, &mut out).unwrap();
+    for (&x, &y) in x.iter().zip(&out) {
+        assert_eq!(interp_with(x, &XS, &YS, left, right), Ok(y));
+    }
+}
+
+#[test]
+fn vector_values() {
+    let xs = [0.0, 10.0];
+    let ys = [Vector3::new(0.0, 0.0, 0.0), Vector3::new(10.0, -10.0, 5.0)];
+    assert_eq!(interp(5.0, &xs, &ys), Ok(Vector3::new(5.0, -5.0, 2.5)));
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn derived_values() {
+    use lerp::Lerp;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Lerp)]
+    struct Color {
+        r: f32,
+        g: f32,
+        b: f32,
+    }
+
+    let xs = [0.0_f32, 0.5, 1.0];
+    let ys = [
+        Color {
+            r: 1.0,
+            g: 0.0,
+            b: 0.0,
+        },
+        Color {
+            r: 0.0,
+            g: 1.0,
+            b: 0.0,
+        },
+        Color {
+            r: 0.0,
+            g: 0.0,
+            b: 1.0,
+        },
+    ];
+    assert_eq!(
+        interp(0.75, &xs, &ys),
+        Ok(Color {
+            r: 0.0,
+            g: 0.5,
+            b: 0.5,
+        })
+    );
+}
@@ -0,0 +1,51 @@
+use lerp::{Lerp, LerpRef};
+use std::ops::{Add, Mul};
+
+/// A heap-backed type, which is deliberately neither `Copy` nor `Clone`.
+#[derive(Debug, PartialEq)]
+struct Samples(Vec<f64>);
+
+impl Add for &Samples {
+    type Output = Samples;
+
+    fn add(self, other: Self) -> Samples {
+        Samples(self.0.iter().zip(&other.0).map(|(a, b)| a + b).collect())
+    }
+}
+
+impl Mul<f64> for &Samples {
+    type Output = Samples;
+
+    fn mul(self, t: f64) -> Samples {
+        Samples(self.0.iter().map(|a| a * t).collect())
+    }
+}
+
+#[test]
+fn lerp_ref_leaves_endpoints() {
+    let a = Samples(vec![0.0, 2.0, -4.0]);
+    let b = Samples(vec![4.0, 6.0, 4.0]);
+    assert_eq!(a.lerp_ref(&b, 0.25), Samples(vec![1.0, 3.0, -2.0]));
+    assert_eq!(a.lerp_ref(&b, 1.5), Samples(vec![6.0, 8.0, 8.0]));
+    assert_eq!(a, Samples(vec![0.0, 2.0, -4.0]));
+    assert_eq!(b, Samples(vec![4.0, 6.0, 4.0]));
+}
+
+#[test]
+fn lerp_assign_without_copy() {
+    let mut value = Samples(vec![0.0, 2.0]);
+    let target = Samples(vec![4.0, 6.0]);
+    value.lerp_assign(&target, 0.5);
+    assert_eq!(value, Samples(vec![2.0, 4.0]));
+    value.lerp_assign(&target, 0.5);
+    assert_eq!(value, Samples(vec![3.0, 5.0]));
+    value.lerp_assign(&target, 1.0);
+    assert_eq!(value, target);
+}
+
+#[test]
+fn matches_lerp() {
+    for &t in &[-1.0, 0.0, 0.3, 1.0, 2.5] {
+        assert_eq!(3.0_f64.lerp_ref(&7.0, t), 3.0_f64.lerp(7.0, t));
+    }
+}
@@ -0,0 +1,102 @@
+use common::close;
+use lerp::{CubicSpline, MonotoneCubic, SplineBoundary, SplineError};
+
+mod common;
+
+fn samples(xs: &[f64], per_interval: usize) -> Vec<f64> {
+    let mut out = Vec::new();
+    for pair in xs.windows(2) {
+        for i in 0..per_interval {
+            out.push(pair[0] + (pair[1] - pair[0]) * i as f64 / per_interval as f64);
+        }
+    }
+    out.push(xs[xs.len() - 1]);
+    out
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        MonotoneCubic::new(vec![0.0], vec![1.0]),
+        Err(SplineError::TooFewPoints)
+    );
+    assert_eq!(
+        MonotoneCubic::new(vec![1.0, 0.0], vec![1.0, 2.0]),
+        Err(SplineError::NotIncreasing { index: 1 })
+    );
+}
+
+#[test]
+fn passes_through_knots() {
+    let xs = vec![0.0, 0.3, 1.0, 1.2, 4.0];
+    let ys = vec![1.0, -2.0, 0.5, 0.5, 3.0];
+    let curve = MonotoneCubic::new(xs.clone(), ys.clone()).unwrap();
+    for (&x, &y) in xs.iter().zip(&ys) {
+        assert!(close(curve.eval(x), y));
+    }
+}
+
+#[test]
+fn preserves_monotonicity() {
+    // a cumulative distribution with a sharp step
+    let xs = vec![0.0, 1.0, 1.5, 1.6, 3.0, 5.0, 5.5];
+    let ys = vec![0.0, 0.01, 0.02, 0.9, 0.95, 0.999, 1.0];
+    let curve = MonotoneCubic::new(xs.clone(), ys).unwrap();
+    let values: Vec<f64> = samples(&xs, 50)
+        .into_iter()
+        .map(|x| curve.eval(x))
+        .collect();
+    assert!(values.windows(2).all(|pair| pair[1] >= pair[0]));
+    assert!(values.iter().all(|&y| (0.0..=1.0).contains(&y)));
+
+    // unlike an ordinary cubic spline
+    let ys = vec![0.0, 0.01, 0.02, 0.9, 0.95, 0.999, 1.0];
+    let spline = CubicSpline::new(xs.clone(), ys, SplineBoundary::Natural).unwrap();
+    let values: Vec<f64> = samples(&xs, 50)
+        .into_iter()
+        .map(|x| spline.eval(x))
+        .collect();
+    assert!(values.windows(2).any(|pair| pair[1] < pair[0]));
+}
+
+#[test]
+fn flat_at_extrema() {
+    let xs = vec![0.0, 1.0, 2.0, 3.0, 4.0];
+    let ys = vec![0.0, 2.0, 1.0, 1.0, 3.0];
+    let curve = MonotoneCubic::new(xs.clone(), ys).unwrap();
+    assert_eq!(curve.slopes()[1], 0.0);
+    assert_eq!(curve.slopes()[2], 0.0);
+    assert_eq!(curve.slopes()[3], 0.0);
+    for x in samples(&xs[1..4], 20) {
+        let y = curve.eval(x);
+        assert!((1.0..=2.0).contains(&y), "{} at {}", y, x);
+    }
+}
+
+#[test]
+fn reproduces_lines() {
+    let xs = vec![0.0, 0.5, 2.0, 2.5, 7.0];
+    let ys: Vec<f64> = xs.iter().map(|x| 1.0 - 2.0 * x).collect();
+    let curve = MonotoneCubic::new(xs, ys).unwrap();
+    for &x in &[-1.0, 0.25, 1.0, 6.0, 8.0] {
+        assert!(close(curve.eval(x), 1.0 - 2.0 * x));
+        assert!(close(curve.derivative(x), -2.0));
+    }
+}
+
+#[test]
+fn continuous_first_derivative() {
+    let xs = vec![0.0, 0.3, 1.0, 1.2, 4.0];
+    let ys = vec![1.0_f64, -2.0, 0.5, 0.7, 3.0];
+    let curve = MonotoneCubic::new(xs.clone(), ys).unwrap();
+    for &x in &xs[1..xs.len() - 1] {
+        assert!((curve.derivative(x - 1e-9) - curve.derivative(x)).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn bounded() {
+    let curve = MonotoneCubic::new(vec![0.0, 1.0, 2.0], vec![0.0, 1.0, 4.0]).unwrap();
+    assert_eq!(curve.eval_bounded(-1.0), 0.0);
+    assert_eq!(curve.eval_bounded(3.0), 4.0);
+}
@@ -0,0 +1,71 @@
+use lerp::{lerp_periodic, Degrees, Lerp, LerpIter, Periodic, Radians};
+use std::f64::consts::PI;
+
+#[test]
+fn shortest_arc() {
+    assert_eq!(Degrees(350.0).lerp(Degrees(10.0), 0.5), Degrees(0.0));
+    assert_eq!(Degrees(10.0).lerp(Degrees(350.0), 0.5), Degrees(0.0));
+    assert_eq!(Degrees(10.0).lerp(Degrees(350.0), 0.25), Degrees(5.0));
+    assert_eq!(Degrees(90.0).lerp(Degrees(180.0), 0.5), Degrees(135.0));
+}
+
+#[test]
+fn endpoints() {
+    assert_eq!(Degrees(350.0).lerp(Degrees(10.0), 0.0), Degrees(350.0));
+    assert_eq!(Degrees(350.0).lerp(Degrees(10.0), 1.0), Degrees(10.0));
+    assert_eq!(Degrees(-90.0).lerp(Degrees(0.0), 0.0), Degrees(270.0));
+}
+
+#[test]
+fn half_turn_goes_positive() {
+    assert_eq!(Degrees(0.0).lerp(Degrees(180.0), 0.5), Degrees(90.0));
+    assert_eq!(Degrees(180.0).lerp(Degrees(0.0), 0.5), Degrees(270.0));
+}
+
+#[test]
+fn unnormalized_inputs() {
+    assert_eq!(Degrees(710.0).lerp(Degrees(-350.0), 0.5), Degrees(0.0));
+}
+
+#[test]
+fn extrapolation_wraps() {
+    assert_eq!(Degrees(350.0).lerp(Degrees(10.0), 2.0), Degrees(30.0));
+    assert_eq!(Degrees(350.0).lerp(Degrees(10.0), -1.0), Degrees(330.0));
+    assert_eq!(
+        Degrees(350.0).lerp_bounded(Degrees(10.0), -1.0),
+        Degrees(350.0)
+    );
+}
+
+#[test]
+fn radians() {
+    let angle = Radians(1.9 * PI).lerp(Radians(0.1 * PI), 0.5);
+    assert!(angle.0 < 1e-12 || (2.0 * PI - angle.0) < 1e-12);
+    let angle = Radians(0.1_f32).lerp(Radians(-0.1), 0.5);
+    assert!(angle.0.abs() < 1e-6 || (std::f32::consts::TAU - angle.0) < 1e-6);
+}
+
+#[test]
+fn periodic() {
+    assert_eq!(lerp_periodic(0.75, 0.25, 0.5, 1.0), 0.0);
+    assert_eq!(lerp_periodic(23.0, 1.0, 0.25, 24.0), 23.5);
+    assert_eq!(
+        Periodic::new(23.0, 24.0).lerp(Periodic::new(1.0, 24.0), 0.75),
+        Periodic::new(0.5, 24.0)
+    );
+}
+
+#[test]
+fn iterators() {
+    let hours: Vec<_> = Periodic::new(22.0, 24.0)
+        .lerp_iter_closed(Periodic::new(2.0, 24.0), 5)
+        .map(|p| p.value)
+        .collect();
+    assert_eq!(hours, vec![22.0, 23.0, 0.0, 1.0, 2.0]);
+
+    let degrees: Vec<_> = Degrees(340.0).lerp_iter(Degrees(20.0), 4).collect();
+    assert_eq!(
+        degrees,
+        vec![Degrees(340.0), Degrees(350.0), Degrees(0.0), Degrees(10.0)]
+    );
+}
@@ -0,0 +1,67 @@
+use lerp::{remap, remap_bounded, Remap, Vector3};
+
+#[test]
+fn reversed_ranges() {
+    // a reversed input range
+    assert_eq!(remap(2.0, (8.0, 0.0), (0.0, 100.0)), Some(75.0));
+    // a reversed output range
+    assert_eq!(remap(2.0, (0.0, 8.0), (100.0, 0.0)), Some(75.0));
+    // both reversed
+    assert_eq!(remap(2.0, (8.0, 0.0), (100.0, 0.0)), Some(25.0));
+
+    assert_eq!(remap_bounded(12.0, (8.0, 0.0), (0.0, 100.0)), Some(0.0));
+    assert_eq!(remap_bounded(-2.0, (8.0, 0.0), (0.0, 100.0)), Some(100.0));
+    assert_eq!(remap_bounded(12.0, (0.0, 8.0), (100.0, 0.0)), Some(0.0));
+
+    let reversed = Remap::new((8.0, 0.0), (100.0, 0.0)).unwrap();
+    assert_eq!(reversed.remap(2.0), 25.0);
+    assert_eq!(reversed.remap_bounded(-2.0), 0.0);
+    assert_eq!(reversed.remap_bounded(12.0), 100.0);
+}
+
+#[test]
+fn empty_range() {
+    assert_eq!(remap(1.0, (3.0, 3.0), (0.0, 1.0)), None);
+    assert_eq!(remap(3.0, (3.0, 3.0), (0.0, 1.0)), None);
+    assert_eq!(remap_bounded(3.0, (3.0, 3.0), (0.0, 1.0)), None);
+    assert!(Remap::<f64, f64>::new((3.0, 3.0), (0.0, 1.0)).is_none());
+}
+
+#[test]
+fn parameter() {
+    let mapping = Remap::new((-2.0, 6.0), (0.0, 1.0)).unwrap();
+    assert_eq!(mapping.parameter(-2.0), 0.0);
+    assert_eq!(mapping.parameter(2.0), 0.5);
+    assert_eq!(mapping.parameter(10.0), 1.5);
+}
+
+#[test]
+fn matches_remap_up_to_rounding() {
+    // the precomputed reciprocal may round differently than a division,
+    // but only in the last bits
+    let from = (0.1, 0.7);
+    let to = (-3.0, 11.0);
+    let mapping = Remap::new(from, to).unwrap();
+    for i in -10..=20 {
+        let value = i as f64 * 0.05;
+        let expected = remap(value, from, to).unwrap();
+        let actual = mapping.remap(value);
+        assert!(
+            (actual - expected).abs() <= 4.0 * f64::EPSILON * expected.abs().max(1.0),
+            "{} vs {}",
+            actual,
+            expected
+        );
+    }
+}
+
+#[test]
+fn other_output_types() {
+    let to = (Vector3::new(0.0, 0.0, 0.0), Vector3::new(2.0, 4.0, 8.0));
+    assert_eq!(
+        remap(15.0, (10.0, 20.0), to),
+        Some(Vector3::new(1.0, 2.0, 4.0))
+    );
+    let mapping = Remap::new((10.0, 20.0), to).unwrap();
+    assert_eq!(mapping.remap(15.0), Vector3::new(1.0, 2.0, 4.0));
+}
@@ -0,0 +1,130 @@
+use lerp::{Lerp, Quaternion, Slerp, Vector3};
+use std::f64::consts::PI;
+
+const EPSILON: f64 = 1e-9;
+
+fn close(a: Vector3<f64>, b: Vector3<f64>) -> bool {
+    (a - b).length() < EPSILON
+}
+
+fn angle_between(a: Vector3<f64>, b: Vector3<f64>) -> f64 {
+    a.dot(b).clamp(-1.0, 1.0).acos()
+}
+
+#[test]
+fn vector_endpoints() {
+    let a = Vector3::new(1.0_f64, 0.0, 0.0);
+    let b = Vector3::new(0.0_f64, 0.6, 0.8);
+    assert!(close(a.slerp(b, 0.0), a));
+    assert!(close(a.slerp(b, 1.0), b));
+    assert!(close(a.nlerp(b, 0.0), a));
+    assert!(close(a.nlerp(b, 1.0), b));
+}
+
+#[test]
+fn vector_constant_angular_speed() {
+    let a = Vector3::new(1.0_f64, 0.0, 0.0);
+    let b = Vector3::new(0.0_f64, 0.6, 0.8);
+    let theta = angle_between(a, b);
+    for i in 0..=10 {
+        let t = i as f64 / 10.0;
+        let v = a.slerp(b, t);
+        assert!((v.length() - 1.0).abs() < EPSILON);
+        assert!((angle_between(a, v) - t * theta).abs() < EPSILON);
+    }
+}
+
+#[test]
+fn vector_lerp_shrinks_but_slerp_does_not() {
+    let a = Vector3::new(1.0_f64, 0.0, 0.0);
+    let b = Vector3::new(0.0_f64, 1.0, 0.0);
+    assert!(a.lerp(b, 0.5).length() < 0.8);
+    assert!((a.slerp(b, 0.5).length() - 1.0).abs() < EPSILON);
+    assert!((a.nlerp(b, 0.5).length() - 1.0).abs() < EPSILON);
+}
+
+#[test]
+fn vector_antipodal() {
+    let a = Vector3::new(0.0_f64, 0.0, 1.0);
+    let b = Vector3::new(0.0_f64, 0.0, -1.0);
+    for i in 0..=10 {
+        let t = i as f64 / 10.0;
+        let slerped = a.slerp(b, t);
+        assert!((slerped.length() - 1.0).abs() < EPSILON, "{:?}", slerped);
+        assert!((angle_between(a, slerped) - t * PI).abs() < EPSILON);
+        let nlerped = a.nlerp(b, t);
+        assert!((nlerped.length() - 1.0).abs() < EPSILON, "{:?}", nlerped);
+    }
+    assert!(a.slerp(b, 0.5).dot(a).abs() < EPSILON);
+    assert!(close(a.slerp(b, 1.0), b));
+    assert!(close(a.nlerp(b, 1.0), b));
+}
+
+#[test]
+fn vector_nearly_opposite() {
+    let theta = 179.0_f64.to_radians();
+    let a = Vector3::new(1.0_f64, 0.0, 0.0);
+    let b = Vector3::new(theta.cos(), theta.sin(), 0.0);
+    assert!(close(a.slerp(b, 1.0), b), "{:?}", a.slerp(b, 1.0));
+    assert!(close(a.nlerp(b, 1.0), b), "{:?}", a.nlerp(b, 1.0));
+    for i in 0..=10 {
+        let t = i as f64 / 10.0;
+        let v = a.slerp(b, t);
+        // the path stays on the great circle through both endpoints
+        assert!(v.z.abs() < EPSILON && v.y >= 0.0, "{:?}", v);
+        assert!((angle_between(a, v) - t * theta).abs() < EPSILON);
+        let v = a.nlerp(b, t);
+        assert!(v.z.abs() < EPSILON && v.y >= 0.0, "{:?}", v);
+    }
+}
+
+#[test]
+fn vector_near_parallel() {
+    let a = Vector3::new(1.0_f64, 0.0, 0.0);
+    let b = Vector3::new(1.0_f64, 1e-10, 0.0).normalize();
+    let v = a.slerp(b, 0.5);
+    assert!(v.x.is_finite() && v.y.is_finite() && v.z.is_finite());
+    assert!((v.length() - 1.0).abs() < EPSILON);
+    assert!(close(a.slerp(a, 0.5), a));
+}
+
+#[test]
+fn quaternion_constant_angular_speed() {
+    let axis = Vector3::new(0.0_f64, 0.6, 0.8);
+    let start = Quaternion::from_axis_angle(axis, 0.2);
+    let end = Quaternion::from_axis_angle(axis, 2.2);
+    for i in 0..=10 {
+        let t = i as f64 / 10.0;
+        let q = start.slerp(end, t);
+        let expect = Quaternion::from_axis_angle(axis, 0.2 + 2.0 * t);
+        assert!((q.dot(expect) - 1.0).abs() < EPSILON, "{} {:?}", t, q);
+    }
+}
+
+#[test]
+fn quaternion_shortest_path() {
+    let z = Vector3::new(0.0_f64, 0.0, 1.0);
+    let start = Quaternion::identity();
+    // a rotation of 270 degrees is the same as one of -90 degrees
+    let end = Quaternion::from_axis_angle(z, 1.5 * PI);
+    assert!(start.dot(end) < 0.0);
+
+    let mid = start.slerp(end, 0.5);
+    let expect = Quaternion::from_axis_angle(z, -0.25 * PI);
+    assert!((mid.dot(expect).abs() - 1.0).abs() < EPSILON);
+
+    let x = Vector3::new(1.0_f64, 0.0, 0.0);
+    assert!(close(start.slerp(end, 1.0).rotate(x), end.rotate(x)));
+    assert!(close(start.nlerp(end, 1.0).rotate(x), end.rotate(x)));
+    assert!(close(start.nlerp(end, 0.5).rotate(x), expect.rotate(x)));
+}
+
+#[test]
+fn quaternion_near_parallel() {
+    let z = Vector3::new(0.0_f64, 0.0, 1.0);
+    let a = Quaternion::from_axis_angle(z, 1.0);
+    let b = Quaternion::from_axis_angle(z, 1.0 + 1e-10);
+    let q = a.slerp(b, 0.5);
+    assert!((q.length() - 1.0).abs() < EPSILON);
+    assert!((a.slerp(a, 0.3).dot(a) - 1.0).abs() < EPSILON);
+}
@@ -0,0 +1,64 @@
+use lerp::{lerp_slice, lerp_slice_in_place, lerp_vec, Floor, LengthMismatch};
+
+#[test]
+fn matches_lerp() {
+    let a: Vec<f64> = (0..100).map(|i| i as f64).collect();
+    let b: Vec<f64> = (0..100).map(|i| (i * i) as f64).collect();
+    let expect: Vec<f64> = a
+        .iter()
+        .zip(&b)
+        .map(|(&a, &b)| lerp::Lerp::lerp(a, b, 0.3))
+        .collect();
+
+    let mut out = vec![0.0; 100];
+    lerp_slice(&a, &b, 0.3, &mut out).unwrap();
+    assert_eq!(out, expect);
+
+    let mut in_place = a.clone();
+    lerp_slice_in_place(&mut in_place, &b, 0.3).unwrap();
+    assert_eq!(in_place, expect);
+
+    assert_eq!(lerp_vec(&a, &b, 0.3).unwrap(), expect);
+}
+
+#[test]
+fn integers() {
+    let mut a = [0_u8, 100, 200];
+    lerp_slice_in_place(&mut a, &[255, 0, 201], Floor(0.5)).unwrap();
+    assert_eq!(a, [127, 50, 200]);
+}
+
+#[test]
+fn empty() {
+    let mut out: [f32; 0] = [];
+    assert_eq!(lerp_slice(&[], &[], 0.5, &mut out), Ok(()));
+}
+
+#[test]
+fn length_mismatch() {
+    let mut out = [9.0; 2];
+    assert_eq!(
+        lerp_slice(&[0.0, 1.0], &[2.0, 3.0], 0.5, &mut out[..1]),
+        Err(LengthMismatch {
+            expected: 2,
+            actual: 1
+        })
+    );
+    assert_eq!(out, [9.0; 2]);
+
+    let mut a = [0.0, 1.0];
+    assert_eq!(
+        lerp_slice_in_place(&mut a, &[1.0, 2.0, 3.0], 0.5),
+        Err(LengthMismatch {
+            expected: 2,
+            actual: 3
+        })
+    );
+    assert_eq!(a, [0.0, 1.0]);
+
+    let err = lerp_vec(&[0.0], &[], 0.5).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "slice length mismatch: expected 1, found 0"
+    );
+}
@@ -0,0 +1,148 @@
+use lerp::{Lerp, LerpStrategy};
+
+const STRATEGIES: [LerpStrategy; 4] = [
+    LerpStrategy::TwoMultiply,
+    LerpStrategy::SingleMultiply,
+    LerpStrategy::Fma,
+    LerpStrategy::Precise,
+];
+
+// pairs of endpoints which exercise same-sign, mixed-sign, and wide-magnitude ranges
+const ENDPOINTS: [(f64, f64); 8] = [
+    (3.0, 5.0),
+    (5.0, 3.0),
+    (-4.931797279177466, -0.4597980805546431),
+    (5.968778811548521, 5.941951252709925),
+    (9.405280730564211, 8.184454563014224),
+    (-0.1, 1e-17),
+    (1e16, 1.0),
+    (-2.5, 7.25),
+];
+
+fn next_up(x: f64) -> f64 {
+    if x >= 0.0 {
+        f64::from_bits(x.to_bits() + 1)
+    } else {
+        f64::from_bits(x.to_bits() - 1)
+    }
+}
+
+fn is_monotonic(strategy: LerpStrategy, a: f64, b: f64, t: f64) -> bool {
+    let x = strategy.lerp(a, b, t);
+    let y = strategy.lerp(a, b, next_up(t));
+    if b > a {
+        y >= x
+    } else {
+        y <= x
+    }
+}
+
+#[test]
+fn all_exact_at_zero() {
+    for &strategy in &STRATEGIES {
+        for &(a, b) in &ENDPOINTS {
+            assert_eq!(strategy.lerp(a, b, 0.0), a, "{:?} {} {}", strategy, a, b);
+        }
+    }
+}
+
+#[test]
+fn two_multiply_matches_default_impl() {
+    for &(a, b) in &ENDPOINTS {
+        for &t in &[-1.5, 0.0, 0.1, 0.29402358494854774, 0.5, 0.9, 1.0, 2.0] {
+            assert_eq!(LerpStrategy::TwoMultiply.lerp(a, b, t), a.lerp(b, t));
+        }
+    }
+}
+
+#[test]
+fn two_multiply_exact_at_one() {
+    for &(a, b) in &ENDPOINTS {
+        assert_eq!(LerpStrategy::TwoMultiply.lerp(a, b, 1.0), b);
+    }
+}
+
+#[test]
+fn two_multiply_not_monotonic() {
+    assert!(!is_monotonic(
+        LerpStrategy::TwoMultiply,
+        5.968778811548521,
+        5.941951252709925,
+        0.8164373705606909,
+    ));
+    assert!(!is_monotonic(
+        LerpStrategy::TwoMultiply,
+        9.405280730564211,
+        8.184454563014224,
+        0.29402358494854774,
+    ));
+}
+
+#[test]
+fn single_multiply_and_fma_inexact_at_one() {
+    assert_ne!(LerpStrategy::SingleMultiply.lerp(1e16, 1.0, 1.0), 1.0);
+    assert_ne!(LerpStrategy::SingleMultiply.lerp(-0.1, 1e-17, 1.0), 1e-17);
+    assert_ne!(LerpStrategy::Fma.lerp(1e16, 1.0, 1.0), 1.0);
+}
+
+#[test]
+fn monotonic_strategies() {
+    let ts = [
+        0.8164373705606909,
+        0.29402358494854774,
+        0.10012914395045203,
+        0.5,
+        0.999999,
+    ];
+    for &strategy in &[
+        LerpStrategy::SingleMultiply,
+        LerpStrategy::Fma,
+        LerpStrategy::Precise,
+    ] {
+        for &(a, b) in &ENDPOINTS {
+            for &t in &ts {
+                assert!(
+                    is_monotonic(strategy, a, b, t),
+                    "{:?} {} {} {}",
+                    strategy,
+                    a,
+                    b,
+                    t
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn precise_exact_at_one() {
+    for &(a, b) in &ENDPOINTS {
+        assert_eq!(LerpStrategy::Precise.lerp(a, b, 1.0), b);
+    }
+}
+
+#[test]
+fn precise_bounded_by_endpoints() {
+    for &(a, b) in &ENDPOINTS {
+        let (lo, hi) = if a < b { (a, b) } else { (b, a) };
+        for i in 0..=1000 {
+            let x = LerpStrategy::Precise.lerp(a, b, i as f64 / 1000.0);
+            assert!(lo <= x && x <= hi, "{} {} {}", a, b, x);
+        }
+    }
+}
+
+#[test]
+fn precise_consistent() {
+    for &t in &[-3.0, 0.0, 0.3, 1.0, 7.5] {
+        assert_eq!(LerpStrategy::Precise.lerp(0.1, 0.1, t), 0.1);
+    }
+}
+
+#[test]
+fn bounded() {
+    for &strategy in &STRATEGIES {
+        assert_eq!(strategy.lerp_bounded(3.0, 5.0, -1.0), 3.0);
+        assert_eq!(strategy.lerp_bounded(3.0, 5.0, 2.0), 5.0);
+    }
+}
@@ -0,0 +1,141 @@
+use lerp::{Animation, Position, Repeat, Timeline, Tween};
+
+fn tween(start: f64, end: f64, duration: f64) -> Tween<f64, f64> {
+    Tween::new(start, end, duration)
+}
+
+#[test]
+fn empty() {
+    let mut timeline: Timeline<f64, f64> = Timeline::new();
+    assert!(timeline.is_empty());
+    assert_eq!(timeline.duration(), Some(0.0));
+    assert_eq!(timeline.end(), 0.0);
+    assert_eq!(timeline.value_at(1.0), None);
+    assert_eq!(timeline.advance(1.0), None);
+    assert!(timeline.is_finished());
+}
+
+#[test]
+fn sequence() {
+    let mut timeline = Timeline::new();
+    let a = timeline.then(tween(0.0, 1.0, 1.0));
+    let b = timeline.then(tween(1.0, 5.0, 2.0));
+    assert_eq!((a, b), (0, 1));
+    assert_eq!(timeline.len(), 2);
+    assert_eq!(timeline.start_of(b), Some(1.0));
+    assert_eq!(timeline.duration(), Some(3.0));
+
+    assert_eq!(timeline.value_at(0.0), Some(0.0));
+    assert_eq!(timeline.value_at(0.5), Some(0.5));
+    assert_eq!(timeline.value_at(1.0), Some(1.0));
+    assert_eq!(timeline.value_at(2.0), Some(3.0));
+    assert_eq!(timeline.value_at(10.0), Some(5.0));
+}
+
+#[test]
+fn parallel() {
+    let mut timeline = Timeline::new();
+    timeline.then(tween(0.0, 1.0, 1.0));
+    let b = timeline.then(tween(0.0, 10.0, 2.0));
+    let c = timeline.with(tween(100.0, 200.0, 4.0));
+    assert_eq!(timeline.start_of(c), timeline.start_of(b));
+    assert_eq!(timeline.duration(), Some(5.0));
+
+    let values: Vec<f64> = timeline.values_at(2.0).collect();
+    assert_eq!(values, vec![1.0, 5.0, 125.0]);
+
+    // the later of two animations starting together wins
+    assert_eq!(timeline.value_at(2.0), Some(125.0));
+}
+
+#[test]
+fn offsets_and_labels() {
+    let mut timeline = Timeline::new();
+    timeline.then(tween(0.0, 1.0, 1.0));
+    timeline.add_label("middle", 0.5);
+    let late = timeline
+        .insert(Position::End, 0.2, tween(0.0, 1.0, 1.0))
+        .unwrap();
+    let early = timeline
+        .insert(Position::Label("middle"), -0.25, tween(0.0, 1.0, 1.0))
+        .unwrap();
+    let absolute = timeline
+        .insert(Position::Time(4.0), 0.0, tween(0.0, 1.0, 1.0))
+        .unwrap();
+    let first = timeline
+        .insert(Position::Start, 0.1, tween(0.0, 1.0, 1.0))
+        .unwrap();
+    assert_eq!(timeline.start_of(late), Some(1.2));
+    assert_eq!(timeline.start_of(early), Some(0.25));
+    assert_eq!(timeline.start_of(absolute), Some(4.0));
+    assert_eq!(timeline.start_of(first), Some(0.1));
+    assert_eq!(timeline.start_of(99), None);
+
+    assert_eq!(
+        timeline.insert(Position::Label("missing"), 0.0, tween(0.0, 1.0, 1.0)),
+        None
+    );
+    assert_eq!(timeline.len(), 5);
+
+    timeline.add_label("middle", 2.0);
+    assert_eq!(timeline.label("middle"), Some(2.0));
+    assert_eq!(timeline.label("missing"), None);
+}
+
+#[test]
+fn before_start() {
+    let mut timeline = Timeline::new();
+    timeline.insert(Position::Time(1.0), 0.0, tween(3.0, 4.0, 1.0));
+    timeline.insert(Position::Time(2.0), 0.0, tween(7.0, 8.0, 1.0));
+    assert_eq!(timeline.value_at(0.0), Some(3.0));
+    assert_eq!(timeline.value_of(1, 0.0), Some(7.0));
+}
+
+#[test]
+fn scrubbing() {
+    let mut timeline = Timeline::new();
+    timeline.then(tween(0.0, 2.0, 2.0));
+    timeline.then(tween(2.0, 0.0, 2.0));
+
+    assert_eq!(timeline.seek(3.0), Some(1.0));
+    assert_eq!(timeline.time(), 3.0);
+    assert_eq!(timeline.seek(0.5), Some(0.5));
+    assert_eq!(timeline.advance(1.0), Some(1.5));
+    assert!(!timeline.is_finished());
+    assert_eq!(timeline.advance(2.5), Some(0.0));
+    assert!(timeline.is_finished());
+}
+
+#[test]
+fn infinite_animations() {
+    let mut timeline = Timeline::new();
+    timeline.then(tween(0.0, 1.0, 1.0).with_repeat(Repeat::Forever));
+    timeline.then(tween(0.0, 1.0, 2.0));
+    assert_eq!(timeline.duration(), None);
+    assert_eq!(timeline.end(), 2.0);
+    timeline.seek(100.0);
+    assert!(!timeline.is_finished());
+}
+
+#[test]
+fn custom_animation() {
+    struct Sine;
+
+    impl Animation<f64> for Sine {
+        type Value = f64;
+
+        fn duration(&self) -> Option<f64> {
+            Some(std::f64::consts::PI)
+        }
+
+        fn value_at(&self, time: f64) -> f64 {
+            time.sin()
+        }
+    }
+
+    let mut timeline = Timeline::new();
+    timeline.then(tween(0.0, 1.0, 1.0));
+    let wave = timeline.then(Sine);
+    assert_eq!(timeline.value_of(wave, 1.0), Some(0.0));
+    assert_eq!(timeline.duration(), Some(1.0 + std::f64::consts::PI));
+}
@@ -0,0 +1,167 @@
+use common::close;
+use lerp::{Key, KeyMode, Track, Vector3, WrapMode};
+
+mod common;
+
+fn track(mode: KeyMode) -> Track<f64, f64> {
+    Track::from_keys(vec![
+        Key::new(0.0, 0.0, mode),
+        Key::new(1.0, 4.0, mode),
+        Key::new(3.0, 2.0, mode),
+        Key::new(4.0, 8.0, mode),
+    ])
+}
+
+#[test]
+fn empty_and_single() {
+    let mut track: Track<f64, f64> = Track::new();
+    assert!(track.is_empty());
+    assert_eq!(track.sample(1.0), None);
+    assert_eq!(track.duration(), 0.0);
+
+    track.insert(Key::new(2.0, 7.0, KeyMode::Cubic));
+    for &wrap in &[WrapMode::Clamp, WrapMode::Loop, WrapMode::PingPong] {
+        track.wrap = wrap;
+        assert_eq!(track.sample(-1.0), Some(7.0));
+        assert_eq!(track.sample(5.0), Some(7.0));
+    }
+}
+
+#[test]
+fn insert_and_remove() {
+    let mut track = Track::new();
+    assert_eq!(track.insert(Key::new(2.0, 20.0, KeyMode::Linear)), None);
+    assert_eq!(track.insert(Key::new(0.0, 0.0, KeyMode::Linear)), None);
+    assert_eq!(track.insert(Key::new(1.0, 10.0, KeyMode::Linear)), None);
+    let times: Vec<f64> = track.keys().iter().map(|key| key.time).collect();
+    assert_eq!(times, vec![0.0, 1.0, 2.0]);
+
+    let replaced = track.insert(Key::new(1.0, 5.0, KeyMode::Step));
+    assert_eq!(replaced, Some(Key::new(1.0, 10.0, KeyMode::Linear)));
+    assert_eq!(track.len(), 3);
+    assert_eq!(track.sample(1.5), Some(5.0));
+
+    assert_eq!(track.remove(1), Some(Key::new(1.0, 5.0, KeyMode::Step)));
+    assert_eq!(track.remove(2), None);
+    assert_eq!(track.sample(1.5), Some(15.0));
+}
+
+#[test]
+fn from_keys_keeps_last_duplicate() {
+    let track = Track::from_keys(vec![
+        Key::new(1.0, 1.0, KeyMode::Linear),
+        Key::new(0.0, 0.0, KeyMode::Linear),
+        Key::new(1.0, 2.0, KeyMode::Linear),
+    ]);
+    assert_eq!(track.len(), 2);
+    assert_eq!(track.sample(1.0), Some(2.0));
+}
+
+#[test]
+fn step() {
+    let track = track(KeyMode::Step);
+    assert_eq!(track.sample(0.5), Some(0.0));
+    assert_eq!(track.sample(1.0), Some(4.0));
+    assert_eq!(track.sample(2.9), Some(4.0));
+    assert_eq!(track.sample(4.0), Some(8.0));
+}
+
+#[test]
+fn linear() {
+    let track = track(KeyMode::Linear);
+    assert_eq!(track.sample(0.5), Some(2.0));
+    assert_eq!(track.sample(2.0), Some(3.0));
+    assert_eq!(track.sample(3.5), Some(5.0));
+}
+
+#[test]
+fn mixed_modes() {
+    let mut track = track(KeyMode::Linear);
+    track.insert(Key::new(1.0, 4.0, KeyMode::Step));
+    assert_eq!(track.sample(0.5), Some(2.0));
+    assert_eq!(track.sample(2.0), Some(4.0));
+    assert_eq!(track.sample(3.5), Some(5.0));
+}
+
+#[test]
+fn cubic_matches_timed_catmull_rom() {
+    let track = track(KeyMode::Cubic);
+    for key in track.keys() {
+        assert_eq!(track.sample(key.time), Some(key.value));
+    }
+
+    // with knots at the key times, values which change linearly in time are reproduced
+    // exactly, even when the keys are unevenly spaced
+    let linear = Track::from_keys(
+        [0.0, 0.5, 2.0, 2.25, 5.0]
+            .iter()
+            .map(|&time| Key::new(time, 3.0 * time - 1.0, KeyMode::Cubic)),
+    );
+    for i in 0..=50 {
+        let time = f64::from(i) / 10.0;
+        assert!(close(linear.sample(time).unwrap(), 3.0 * time - 1.0));
+    }
+
+    // smooth across keys
+    let h = 1e-7;
+    for key in &track.keys()[1..3] {
+        let before = (key.value - track.sample(key.time - h).unwrap()) / h;
+        let after = (track.sample(key.time + h).unwrap() - key.value) / h;
+        assert!((before - after).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn cubic_may_overshoot() {
+    let track = Track::from_keys(vec![
+        Key::new(0.0, 0.0, KeyMode::Cubic),
+        Key::new(1.0, 10.0, KeyMode::Cubic),
+        Key::new(2.0, 10.0, KeyMode::Cubic),
+        Key::new(3.0, 0.0, KeyMode::Cubic),
+    ]);
+    assert!(close(track.sample(1.5).unwrap(), 11.25));
+}
+
+#[test]
+fn wrap_modes() {
+    let mut track = track(KeyMode::Linear);
+    assert_eq!(track.duration(), 4.0);
+    assert_eq!(track.sample(-1.0), Some(0.0));
+    assert_eq!(track.sample(5.0), Some(8.0));
+
+    track.wrap = WrapMode::Loop;
+    assert_eq!(track.sample(4.5), Some(2.0));
+    assert_eq!(track.sample(-3.5), Some(2.0));
+    assert_eq!(track.sample(8.0), Some(0.0));
+
+    track.wrap = WrapMode::PingPong;
+    assert_eq!(track.sample(4.0), Some(8.0));
+    assert_eq!(track.sample(4.5), Some(5.0));
+    assert_eq!(track.sample(7.5), Some(2.0));
+    assert_eq!(track.sample(8.0), Some(0.0));
+    assert_eq!(track.sample(-0.5), Some(2.0));
+}
+
+#[test]
+fn sampler_matches_sample() {
+    for &wrap in &[WrapMode::Clamp, WrapMode::Loop, WrapMode::PingPong] {
+        let mut track = track(KeyMode::Cubic);
+        track.wrap = wrap;
+        let mut sampler = track.sampler();
+        let forward = (-40..=120).map(|i| f64::from(i) / 10.0);
+        let backward = (-40..=120).rev().map(|i| f64::from(i) / 10.0);
+        let jumping = [3.2, 0.1, 7.7, 3.9, 1.0, 1.0, -2.5];
+        for time in forward.chain(backward).chain(jumping.iter().copied()) {
+            assert_eq!(sampler.sample(time), track.sample(time), "{}", time);
+        }
+    }
+}
+
+#[test]
+fn vector_values() {
+    let track = Track::from_keys(vec![
+        Key::new(0.0, Vector3::new(0.0, 0.0, 0.0), KeyMode::Linear),
+        Key::new(2.0, Vector3::new(2.0, 4.0, 6.0), KeyMode::Linear),
+    ]);
+    assert_eq!(track.sample(1.0), Some(Vector3::new(1.0, 2.0, 3.0)));
+}
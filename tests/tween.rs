@@ -0,0 +1,128 @@
+use lerp::easing::{self, Easing};
+use lerp::{Animation, Repeat, Timeline, Tween, Vector3};
+
+#[test]
+fn linear_playback() {
+    let mut tween = Tween::new(2.0, 6.0, 4.0);
+    assert_eq!(tween.value_at(0.0), 2.0);
+    assert_eq!(tween.advance(1.0), 3.0);
+    assert_eq!(tween.advance(2.0), 5.0);
+    assert!(!tween.is_finished());
+    assert_eq!(tween.advance(1.0), 6.0);
+    assert!(tween.is_finished());
+    assert_eq!(tween.elapsed(), 4.0);
+}
+
+#[test]
+fn holds_final_value() {
+    let tween = Tween::new(0.0, 1.0, 1.0).with_easing(easing::back_out);
+    assert!(tween.value_at(0.8) > 1.0);
+    assert_eq!(tween.value_at(1.0), 1.0);
+    assert_eq!(tween.value_at(50.0), 1.0);
+    assert_eq!(tween.value_at(-5.0), 0.0);
+}
+
+#[test]
+fn any_easing() {
+    let steps = 4.0;
+    let tween = Tween::new(0.0, 8.0, 1.0).with_easing(move |t: f64| (t * steps).floor() / steps);
+    assert_eq!(tween.value_at(0.3), 2.0);
+
+    struct Power(i32);
+    impl Easing<f64> for Power {
+        fn ease(&self, t: f64) -> f64 {
+            t.powi(self.0)
+        }
+    }
+    let tween = Tween::new(0.0, 8.0, 1.0).with_easing(Power(3));
+    assert_eq!(tween.value_at(0.5), 1.0);
+    assert_eq!(Animation::duration(&tween), Some(1.0));
+
+    let mut timeline = Timeline::new();
+    timeline.then(tween);
+    assert_eq!(timeline.value_at(0.5), Some(1.0));
+}
+
+#[test]
+fn delay() {
+    let tween = Tween::new(0.0, 8.0, 2.0).with_delay(3.0);
+    assert_eq!(tween.total_duration(), Some(5.0));
+    assert_eq!(tween.value_at(1.0), 0.0);
+    assert_eq!(tween.value_at(3.0), 0.0);
+    assert_eq!(tween.value_at(4.0), 4.0);
+    assert_eq!(tween.value_at(5.0), 8.0);
+}
+
+#[test]
+fn repeat() {
+    let tween = Tween::new(0.0, 10.0, 1.0).with_repeat(Repeat::Times(2));
+    assert_eq!(tween.total_duration(), Some(3.0));
+    assert_eq!(tween.value_at(0.5), 5.0);
+    assert_eq!(tween.value_at(1.5), 5.0);
+    assert_eq!(tween.value_at(2.25), 2.5);
+    assert_eq!(tween.value_at(3.0), 10.0);
+}
+
+#[test]
+fn yoyo() {
+    let tween = Tween::new(0.0, 10.0, 1.0)
+        .with_repeat(Repeat::Times(2))
+        .with_yoyo(true);
+    assert_eq!(tween.value_at(0.25), 2.5);
+    assert_eq!(tween.value_at(1.25), 7.5);
+    assert_eq!(tween.value_at(2.25), 2.5);
+    assert_eq!(tween.value_at(3.0), 10.0);
+
+    let back = tween.with_repeat(Repeat::Times(1));
+    assert_eq!(back.value_at(2.0), 0.0);
+}
+
+#[test]
+fn forever() {
+    let mut tween = Tween::new(0.0, 4.0, 1.0)
+        .with_repeat(Repeat::Forever)
+        .with_yoyo(true);
+    assert_eq!(tween.total_duration(), None);
+    assert_eq!(tween.advance(1000.25), 1.0);
+    assert!(!tween.is_finished());
+    assert_eq!(tween.advance(1.0), 3.0);
+}
+
+#[test]
+fn zero_duration() {
+    let mut tween = Tween::new(1.0, 2.0, 0.0);
+    assert_eq!(tween.value_at(-0.1), 1.0);
+    assert!(tween.is_finished());
+    assert_eq!(tween.value_at(0.0), 2.0);
+    assert_eq!(tween.advance(0.0), 2.0);
+    assert_eq!(tween.advance(0.1), 2.0);
+
+    let delayed = Tween::new(1.0, 2.0, 0.0).with_delay(1.0);
+    assert_eq!(delayed.value_at(0.5), 1.0);
+    assert_eq!(delayed.value_at(1.0), 2.0);
+}
+
+#[test]
+fn reset() {
+    let mut tween = Tween::new(0.0, 1.0, 1.0);
+    tween.advance(2.0);
+    assert!(tween.is_finished());
+    tween.reset();
+    assert!(!tween.is_finished());
+    assert_eq!(tween.advance(0.5), 0.5);
+}
+
+#[test]
+fn vector_values_with_f32_time() {
+    let tween = Tween::new(
+        Vector3::new(0.0_f32, 0.0, 0.0),
+        Vector3::new(2.0, 4.0, 8.0),
+        2.0,
+    )
+    .with_easing(easing::linear);
+    assert_eq!(tween.value_at(1.0), Vector3::new(1.0, 2.0, 4.0));
+    assert_eq!(tween.start(), Vector3::new(0.0, 0.0, 0.0));
+    assert_eq!(tween.end(), Vector3::new(2.0, 4.0, 8.0));
+    assert_eq!(tween.duration(), 2.0);
+    assert_eq!(tween.delay(), 0.0);
+}